@@ -10,7 +10,10 @@
 //! The stable integration surface is:
 //! - [`GameCli`]: implemented by a game crate
 //! - [`PatchOutcome`]: the structured result passed across the boundary
+//! - [`MigrationInfo`] / [`FixInfo`]: patch descriptions returned by [`GameCli`]
+//! - [`SaveInfo`]: the save summary returned by [`GameCli::describe_save`]
 //! - [`run`] / [`run_with_args`]: the generic CLI runner
+//! - [`run_and_report`] / [`exit_code`] / [`exit_code_for_error`]: process exit code policy
 //!
 //! Everything else in this crate is considered internal and may change.
 //!
@@ -42,14 +45,23 @@
 //! }
 //! ```
 
+use std::borrow::Cow;
+use std::cell::RefCell;
 use std::ffi::OsString;
 use std::fs;
-use std::io::IsTerminal;
-use std::path::PathBuf;
+use std::io::{self, IsTerminal, Read, Write};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
-use gb_save_core::{PatchLogEntry, PatchLogLevel};
+use clap::parser::ValueSource;
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use gb_save_core::{
+    Address, PatchLogEntry, PatchLogLevel, PatchLogSink, SaveBinary, SaveError, WritePatchLogSink,
+};
 
 /// Game-specific glue for the generic CLI.
 ///
@@ -93,6 +105,40 @@ pub trait GameCli {
     /// ```
     fn detect_version(bytes: &[u8]) -> Result<u16>;
 
+    /// Checks that a save is valid without patching it.
+    ///
+    /// Used by the `validate` command. The default implementation only requires
+    /// [`detect_version`](GameCli::detect_version) to succeed; override it to also check
+    /// checksums, sizes, or magic bytes.
+    ///
+    /// # Errors
+    /// Returns an error describing why the save is invalid.
+    fn validate(bytes: &[u8]) -> Result<()> {
+        Self::detect_version(bytes).map(|_| ())
+    }
+
+    /// Oldest save version this game can read or produce.
+    ///
+    /// The default implementation returns `u16::MIN`.
+    fn min_supported_version() -> u16 {
+        u16::MIN
+    }
+
+    /// Newest save version this game can read or produce.
+    ///
+    /// The default implementation returns `u16::MAX`.
+    fn max_supported_version() -> u16 {
+        u16::MAX
+    }
+
+    /// Returns a human-readable description of a save version (for example, the ROM release it
+    /// corresponds to).
+    ///
+    /// Shown by the `version` and `info` commands. The default implementation returns `None`.
+    fn describe_version(_version: u16) -> Option<String> {
+        None
+    }
+
     /// Applies either a migration (`dev_type == 0`) or a fix patch (`dev_type != 0`).
     ///
     /// # Example
@@ -112,7 +158,8 @@ pub trait GameCli {
 
     /// Applies a patch and returns a structured outcome.
     ///
-    /// The default implementation calls [`patch`] and returns an outcome with no logs.
+    /// The default implementation calls [`patch`] and returns an outcome with no logs and no
+    /// applied patch ids.
     ///
     /// # Example
     /// ```
@@ -133,20 +180,146 @@ pub trait GameCli {
                 ok: true,
                 bytes: Some(bytes),
                 error: None,
+                save_error: None,
                 logs: Vec::new(),
+                applied_patch_ids: Vec::new(),
             },
             Err(e) => PatchOutcome {
                 ok: false,
                 bytes: None,
                 error: Some(e.to_string()),
+                save_error: find_save_error(&e),
                 logs: Vec::new(),
+                applied_patch_ids: Vec::new(),
             },
         }
     }
+
+    /// Applies a patch, pushing each log entry to `log` as soon as it is produced.
+    ///
+    /// Used by `--log-file` and `--format ndjson`. The default implementation calls
+    /// [`patch_with_log`](GameCli::patch_with_log) and replays its logs into `log` afterwards;
+    /// override it to stream entries while patching. The returned outcome should still carry every
+    /// entry in [`PatchOutcome::logs`].
+    fn patch_with_sink(
+        bytes: Vec<u8>,
+        target: u16,
+        dev_type: u8,
+        log: &mut dyn PatchLogSink,
+    ) -> PatchOutcome {
+        let outcome = Self::patch_with_log(bytes, target, dev_type);
+        for entry in &outcome.logs {
+            log.push(entry.clone());
+        }
+        outcome
+    }
+
+    /// Applies a patch, streaming logs to `log` and calling `on_progress(done, total)` as
+    /// migration steps complete.
+    ///
+    /// Used by `--format ndjson`, which prints one line per log entry and per progress call, in
+    /// the order they happen. The default implementation never reports progress and delegates to
+    /// [`patch_with_sink`](GameCli::patch_with_sink).
+    fn patch_with_progress(
+        bytes: Vec<u8>,
+        target: u16,
+        dev_type: u8,
+        log: &mut dyn PatchLogSink,
+        on_progress: impl FnMut(usize, usize),
+    ) -> PatchOutcome {
+        let _ = on_progress;
+        Self::patch_with_sink(bytes, target, dev_type, log)
+    }
+
+    /// Lists the migration patches this game supports.
+    ///
+    /// The default implementation returns an empty list.
+    fn list_migrations() -> Vec<MigrationInfo> {
+        Vec::new()
+    }
+
+    /// Lists the fix patches this game supports.
+    ///
+    /// The default implementation returns an empty list.
+    fn list_fixes() -> Vec<FixInfo> {
+        Vec::new()
+    }
+
+    /// Verifies the checksum embedded in a patched save.
+    ///
+    /// Used by `patch --verify-checksum`. The default implementation accepts every save.
+    ///
+    /// # Errors
+    /// Returns an error if the checksum does not match the save contents.
+    fn verify_checksum(_bytes: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    /// Resolves a fix patch id (as given to `patch --patch-id`) to its `dev_type`.
+    ///
+    /// The default implementation knows no ids and returns `None`.
+    fn resolve_patch_id(_id: &str) -> Option<u8> {
+        None
+    }
+
+    /// Summarizes a save for the `info` command.
+    ///
+    /// The default implementation reports only the detected version and the size.
+    ///
+    /// # Errors
+    /// Returns an error if the save cannot be recognized.
+    fn describe_save(bytes: &[u8]) -> Result<SaveInfo> {
+        Ok(SaveInfo {
+            version: Self::detect_version(bytes)?,
+            size_bytes: bytes.len(),
+            checksum_ok: None,
+            notes: Vec::new(),
+        })
+    }
+}
+
+/// Save summary shown by the `info` command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveInfo {
+    /// Detected save version.
+    pub version: u16,
+    /// Save size in bytes.
+    pub size_bytes: usize,
+    /// Whether the embedded checksums pass, or `None` if they were not checked.
+    pub checksum_ok: Option<bool>,
+    /// Free-form validation warnings or remarks.
+    pub notes: Vec<String>,
+}
+
+/// Describes a migration patch for the `list-patches` command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationInfo {
+    /// Stable patch identifier.
+    pub id: &'static str,
+    /// Save version the migration reads.
+    pub from_version: u16,
+    /// Save version the migration produces.
+    pub to_version: u16,
+    /// Human-readable summary (may be empty).
+    pub description: &'static str,
+}
+
+/// Describes a fix patch for the `list-patches` command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixInfo {
+    /// Stable patch identifier.
+    pub id: &'static str,
+    /// The `--dev-type` value that selects this fix.
+    pub dev_type: u8,
+    /// Human-readable summary (may be empty).
+    pub description: &'static str,
 }
 
 /// Result of a patch operation.
 ///
+/// With the `serde` feature this can be serialized and deserialized. `save_error` is not
+/// serialized and deserializes as `None`.
+///
 /// # Example
 /// ```
 /// use gb_save_cli::PatchOutcome;
@@ -155,29 +328,131 @@ pub trait GameCli {
 ///     ok: true,
 ///     bytes: Some(vec![1, 2, 3]),
 ///     error: None,
+///     save_error: None,
 ///     logs: Vec::new(),
+///     applied_patch_ids: Vec::new(),
 /// };
 /// assert!(outcome.ok);
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PatchOutcome {
     /// Whether the patch succeeded.
     pub ok: bool,
     /// Patched save bytes, if patching succeeded.
+    ///
+    /// With the `serde` feature this is a base64 string under `bytes_base64`, omitted when `None`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "bytes_base64",
+            with = "bytes_base64",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
     pub bytes: Option<Vec<u8>>,
     /// Human-readable error message, if patching failed.
     pub error: Option<String>,
+    /// The typed error behind `error`, if there is one; the CLI uses it to pick the exit code.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub save_error: Option<SaveError>,
     /// Structured logs emitted during patching.
     pub logs: Vec<PatchLogEntry>,
+    /// Ids of the patches that were applied, in order (empty if the game does not report them).
+    #[cfg_attr(feature = "serde", serde(rename = "applied_patches", default))]
+    pub applied_patch_ids: Vec<Cow<'static, str>>,
+}
+
+impl PatchOutcome {
+    /// Returns a copy keeping only log entries at or above `min_level`.
+    ///
+    /// # Example
+    /// ```
+    /// use gb_save_cli::PatchOutcome;
+    /// use gb_save_core::{PatchLogEntry, PatchLogLevel};
+    ///
+    /// let outcome = PatchOutcome {
+    ///     ok: true,
+    ///     bytes: None,
+    ///     error: None,
+    ///     save_error: None,
+    ///     logs: vec![PatchLogEntry::info("a", "x"), PatchLogEntry::warning("b", "y")],
+    ///     applied_patch_ids: Vec::new(),
+    /// };
+    /// assert_eq!(outcome.filter_logs_by_level(PatchLogLevel::Warning).logs.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn filter_logs_by_level(&self, min_level: PatchLogLevel) -> PatchOutcome {
+        PatchOutcome {
+            ok: self.ok,
+            bytes: self.bytes.clone(),
+            error: self.error.clone(),
+            save_error: self.save_error.clone(),
+            logs: self
+                .logs
+                .iter()
+                .filter(|entry| entry.level >= min_level)
+                .cloned()
+                .collect(),
+            applied_patch_ids: self.applied_patch_ids.clone(),
+        }
+    }
+
+    /// Returns the error-level log entries.
+    #[must_use]
+    pub fn logs_with_errors(&self) -> Vec<&PatchLogEntry> {
+        self.logs
+            .iter()
+            .filter(|entry| entry.level == PatchLogLevel::Error)
+            .collect()
+    }
+
+    /// Returns true if patching failed or any log entry is an error.
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        !self.ok || self.error.is_some() || !self.logs_with_errors().is_empty()
+    }
+}
+
+#[cfg(feature = "serde")]
+mod bytes_base64 {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        bytes: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match bytes {
+            Some(bytes) => serializer.serialize_str(&STANDARD.encode(bytes)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<u8>>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|text| STANDARD.decode(text).map_err(serde::de::Error::custom))
+            .transpose()
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 enum OutputFormat {
     Human,
     Json,
+    /// One JSON object per line: patch logs stream as they are produced, then a result line.
+    Ndjson,
+    /// The JSON layout rendered as a TOML document; `null` fields are omitted.
+    Toml,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 enum ColorMode {
     Auto,
     Always,
@@ -201,10 +476,29 @@ struct Cli {
     #[arg(long, value_enum, default_value_t = OutputFormat::Human, global = true)]
     format: OutputFormat,
 
-    /// Colored output policy (human format only).
+    /// Colored output policy (human format only; other formats never use color).
     #[arg(long, value_enum, default_value_t = ColorMode::Auto, global = true)]
     color: ColorMode,
 
+    /// Config file with default flag values (see `config example`).
+    ///
+    /// The `GB_SAVE_FORMAT`, `GB_SAVE_COLOR`, `GB_SAVE_QUIET` and `GB_SAVE_VERBOSE` environment
+    /// variables take precedence over the config file but not over explicit flags.
+    ///
+    /// Defaults to `$XDG_CONFIG_HOME/gb-save-patcher/config.toml` (or `~/.config/...`) if it exists.
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Prints at most this many patch log entries, followed by a warning counting the rest.
+    ///
+    /// Applies to human, JSON and TOML output; `--log-file` still receives every entry.
+    #[arg(long, global = true, value_name = "N")]
+    max_log_entries: Option<usize>,
+
+    /// Appends every patch log entry to this file as NDJSON, regardless of `--quiet`/`--verbose`.
+    #[arg(long, global = true)]
+    log_file: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Command,
 }
@@ -214,22 +508,166 @@ enum Command {
     /// Prints the detected save version.
     Version { path: PathBuf },
 
-    /// Applies a patch and writes the output.
-    Patch {
-        #[arg(long = "in")]
-        input: PathBuf,
+    /// Checks whether a save file is valid without patching it.
+    Validate { path: PathBuf },
+
+    /// Prints a summary of a save file.
+    Info { path: PathBuf },
 
-        #[arg(long = "out")]
-        output: PathBuf,
+    /// Lists the migrations and fix patches this game supports.
+    ListPatches,
 
+    /// Shows the bytes that differ between two save files.
+    Diff {
         #[arg(long)]
-        target: u16,
+        before: PathBuf,
+
+        #[arg(long)]
+        after: PathBuf,
 
+        /// Number of unchanged bytes to show around each change (human format only).
         #[arg(long, default_value_t = 0)]
-        dev_type: u8,
+        context: usize,
+    },
+
+    /// Applies a patch and writes the output.
+    Patch(PatchArgs),
+
+    /// Patches every matching save in a directory.
+    BatchPatch(BatchPatchArgs),
+
+    /// Prints a shell completion script to stdout.
+    Completions {
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+
+    /// Config file helpers.
+    Config {
+        #[command(subcommand)]
+        action: ConfigCommand,
     },
 }
 
+#[derive(Debug, Subcommand)]
+enum ConfigCommand {
+    /// Prints an example config file documenting every supported key.
+    Example,
+}
+
+/// Global flag defaults loaded from a TOML config file.
+#[derive(Debug, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    quiet: Option<bool>,
+    verbose: Option<u8>,
+    format: Option<OutputFormat>,
+    color: Option<ColorMode>,
+}
+
+const CONFIG_EXAMPLE: &str = r#"# gb-save-patcher config file.
+# Every key is optional; flags given on the command line take precedence.
+
+# Only print errors.
+quiet = false
+
+# 0 prints warnings and errors, 1 adds info logs, 2 adds extra details.
+verbose = 0
+
+# Output format for logs and errors: "human", "json", "ndjson" or "toml".
+format = "human"
+
+# Colored output policy: "auto", "always" or "never".
+color = "auto"
+"#;
+
+#[derive(Debug, clap::Args)]
+#[command(group(clap::ArgGroup::new("patch_selector").args(["dev_type", "patch_id"])))]
+struct PatchArgs {
+    /// Input save path, or `-` to read from stdin.
+    #[arg(long = "in")]
+    input: PathBuf,
+
+    /// Output save path, or `-` to write to stdout.
+    #[arg(long = "out", required_unless_present_any = ["output_dir", "output_gz"])]
+    output: Option<PathBuf>,
+
+    /// Gzip-compresses the output and writes it to this path, or `-` for stdout.
+    #[arg(long = "out-gz", conflicts_with_all = ["output", "output_dir"])]
+    output_gz: Option<PathBuf>,
+
+    /// Writes the output into this directory under the input's file name (created if missing).
+    #[arg(long, conflicts_with = "output")]
+    output_dir: Option<PathBuf>,
+
+    #[arg(long)]
+    target: u16,
+
+    /// Fix patch to apply; omit (or pass 0) to run a migration instead.
+    #[arg(long)]
+    dev_type: Option<u8>,
+
+    /// Fix patch to apply, by id (see `list-patches`).
+    #[arg(long)]
+    patch_id: Option<String>,
+
+    /// Runs the full patch pipeline but skips writing the output.
+    ///
+    /// No files are written; use this to validate a patch plan before committing.
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Copies the original input to `<out>.bak` before writing the output.
+    #[arg(long)]
+    backup: bool,
+
+    /// Writes the backup to this path instead of `<out>.bak`.
+    #[arg(long, requires = "backup")]
+    backup_path: Option<PathBuf>,
+
+    /// Verifies the patched save's checksum and refuses to write it on mismatch.
+    #[arg(long)]
+    verify_checksum: bool,
+
+    /// Verifies the checksum of the input before patching and of the output before writing.
+    ///
+    /// Not supported with stdin input (`--in -`).
+    #[arg(long)]
+    paranoid: bool,
+
+    /// Re-runs the patch whenever the input file changes, until interrupted (Ctrl-C).
+    ///
+    /// Human output only.
+    #[cfg(feature = "watch")]
+    #[arg(long)]
+    watch: bool,
+}
+
+#[derive(Debug, clap::Args)]
+struct BatchPatchArgs {
+    /// Directory containing the saves to patch.
+    #[arg(long)]
+    input_dir: PathBuf,
+
+    /// Directory the patched saves are written to (created if missing).
+    #[arg(long)]
+    output_dir: PathBuf,
+
+    #[arg(long)]
+    target: u16,
+
+    #[arg(long, default_value_t = 0)]
+    dev_type: u8,
+
+    /// File name pattern; `*` matches any run of characters and `?` matches one.
+    #[arg(long, default_value = "*.sav")]
+    pattern: String,
+
+    /// Stops at the first file that fails to patch.
+    #[arg(long)]
+    fail_fast: bool,
+}
+
 fn should_print(level: PatchLogLevel, quiet: bool, verbose: u8) -> bool {
     if quiet {
         return level == PatchLogLevel::Error;
@@ -282,28 +720,198 @@ fn print_logs_human(logs: &[PatchLogEntry], quiet: bool, verbose: u8, color_mode
     }
 }
 
-fn print_outcome_json(outcome: &PatchOutcome) {
-    let logs = outcome
-        .logs
-        .iter()
-        .map(|entry| {
-            let level = match entry.level {
-                PatchLogLevel::Info => "info",
-                PatchLogLevel::Warning => "warn",
-                PatchLogLevel::Error => "error",
-            };
+/// Keeps the first `max` entries and appends a warning for any that were dropped.
+///
+/// Returns the entries to print and whether anything was omitted.
+fn truncate_logs(logs: &[PatchLogEntry], max: Option<usize>) -> (Cow<'_, [PatchLogEntry]>, bool) {
+    match max {
+        Some(max) if logs.len() > max => {
+            let mut kept = logs[..max].to_vec();
+            kept.push(PatchLogEntry::warning(
+                "cli",
+                format!("output truncated: {} entries omitted", logs.len() - max),
+            ));
+            (Cow::Owned(kept), true)
+        }
+        _ => (Cow::Borrowed(logs), false),
+    }
+}
 
-            serde_json::json!({
-                "level": level,
-                "source": entry.source,
-                "message": entry.message,
-            })
+fn print_applied_patches_human(outcome: &PatchOutcome, quiet: bool, verbose: u8) {
+    if quiet || verbose == 0 || outcome.applied_patch_ids.is_empty() {
+        return;
+    }
+    eprintln!("applied patches: {}", outcome.applied_patch_ids.join(", "));
+}
+
+fn log_entry_json(entry: &PatchLogEntry) -> serde_json::Value {
+    serde_json::json!({
+        "level": entry.level.as_str(),
+        "source": entry.source,
+        "message": entry.message,
+    })
+}
+
+fn open_log_file(path: Option<&Path>) -> Option<WritePatchLogSink<fs::File>> {
+    let path = path?;
+    match fs::OpenOptions::new().create(true).append(true).open(path) {
+        Ok(file) => Some(WritePatchLogSink::with_format(
+            file,
+            PatchLogLevel::Info,
+            |entry| log_entry_json(entry).to_string(),
+        )),
+        Err(e) => {
+            eprintln!("warning: cannot open log file {}: {e}", path.display());
+            None
+        }
+    }
+}
+
+/// Destination for NDJSON lines, shared between a [`CliLogSink`] and its progress callback.
+type NdjsonOut = Rc<RefCell<dyn Write>>;
+
+/// Streams patch logs to the `--log-file` and, in NDJSON mode, to stdout/stderr.
+struct CliLogSink {
+    file: Option<WritePatchLogSink<fs::File>>,
+    ndjson: Option<NdjsonOut>,
+}
+
+impl CliLogSink {
+    fn new(cli: &Cli, to_stderr: bool) -> Self {
+        let ndjson = (cli.format == OutputFormat::Ndjson).then(|| -> NdjsonOut {
+            if to_stderr {
+                Rc::new(RefCell::new(io::stderr()))
+            } else {
+                Rc::new(RefCell::new(io::stdout()))
+            }
+        });
+        Self {
+            file: open_log_file(cli.log_file.as_deref()),
+            ndjson,
+        }
+    }
+}
+
+impl PatchLogSink for CliLogSink {
+    fn push(&mut self, entry: PatchLogEntry) {
+        if let Some(out) = &self.ndjson {
+            let mut line = log_entry_json(&entry);
+            line["type"] = serde_json::Value::String("log".to_string());
+            write_json_line(&mut *out.borrow_mut(), &line);
+        }
+        if let Some(file) = &mut self.file {
+            file.push(entry);
+        }
+    }
+}
+
+impl CliLogSink {
+    /// Patches through the hook that matches the output format.
+    ///
+    /// NDJSON interleaves progress lines with log lines as they happen; other formats stream
+    /// logs through [`GameCli::patch_with_sink`].
+    fn patch<G: GameCli>(&mut self, bytes: Vec<u8>, target: u16, dev_type: u8) -> PatchOutcome {
+        let Some(out) = self.ndjson.clone() else {
+            return G::patch_with_sink(bytes, target, dev_type, self);
+        };
+
+        G::patch_with_progress(bytes, target, dev_type, self, |done, total| {
+            let line = serde_json::json!({ "type": "progress", "done": done, "total": total });
+            write_json_line(&mut *out.borrow_mut(), &line);
         })
-        .collect::<Vec<_>>();
+    }
+
+    /// Prints the final NDJSON line for a patch, after the streamed logs and progress.
+    fn print_result(&self, obj: serde_json::Map<String, serde_json::Value>) {
+        if let Some(out) = &self.ndjson {
+            print_outcome_ndjson(obj, &mut *out.borrow_mut());
+        }
+    }
+}
+
+fn print_json_line(value: &serde_json::Value, to_stderr: bool) {
+    if to_stderr {
+        eprintln!("{value}");
+    } else {
+        println!("{value}");
+    }
+}
+
+fn write_json_line(out: &mut dyn Write, value: &serde_json::Value) {
+    // Output errors (such as a closed pipe) are ignored instead of panicking like `println!`.
+    let _ = writeln!(out, "{value}");
+}
+
+/// Prints the final NDJSON line for a patch; logs were already streamed by [`CliLogSink`].
+fn print_outcome_ndjson(mut obj: serde_json::Map<String, serde_json::Value>, out: &mut dyn Write) {
+    obj.remove("logs");
+    obj.insert(
+        "type".to_string(),
+        serde_json::Value::String("result".to_string()),
+    );
+    write_json_line(out, &serde_json::Value::Object(obj));
+}
+
+/// Drops `null` values, which TOML cannot represent.
+fn strip_nulls(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(obj) => serde_json::Value::Object(
+            obj.into_iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k, strip_nulls(v)))
+                .collect(),
+        ),
+        serde_json::Value::Array(items) => serde_json::Value::Array(
+            items
+                .into_iter()
+                .filter(|v| !v.is_null())
+                .map(strip_nulls)
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+fn outcome_toml(value: serde_json::Value) -> Result<String> {
+    toml::to_string(&strip_nulls(value)).context("render TOML output")
+}
+
+fn print_outcome_toml(value: serde_json::Value, to_stderr: bool) -> Result<()> {
+    let text = outcome_toml(value)?;
+    if to_stderr {
+        eprint!("{text}");
+    } else {
+        print!("{text}");
+    }
+    Ok(())
+}
+
+/// Prints a result object for `--format json` or `--format toml`.
+fn print_structured(format: OutputFormat, value: serde_json::Value, to_stderr: bool) -> Result<()> {
+    if format == OutputFormat::Toml {
+        return print_outcome_toml(value, to_stderr);
+    }
+    print_json_line(&value, to_stderr);
+    Ok(())
+}
+
+fn outcome_json(
+    outcome: &PatchOutcome,
+    max_log_entries: Option<usize>,
+) -> serde_json::Map<String, serde_json::Value> {
+    let (logs, truncated) = truncate_logs(&outcome.logs, max_log_entries);
+    let logs = logs.iter().map(log_entry_json).collect::<Vec<_>>();
 
     let mut obj = serde_json::Map::new();
     obj.insert("ok".to_string(), serde_json::Value::Bool(outcome.ok));
     obj.insert("logs".to_string(), serde_json::Value::Array(logs));
+    if truncated {
+        obj.insert("logs_truncated".to_string(), serde_json::Value::Bool(true));
+    }
+    obj.insert(
+        "applied_patches".to_string(),
+        serde_json::Value::from(outcome.applied_patch_ids.clone()),
+    );
 
     if let Some(bytes) = &outcome.bytes {
         obj.insert(
@@ -319,113 +927,2732 @@ fn print_outcome_json(outcome: &PatchOutcome) {
         );
     }
 
-    println!("{}", serde_json::Value::Object(obj));
+    obj
 }
 
-/// Runs the CLI using the game-specific implementation `G`.
-///
-/// # Example
-/// ```no_run
-/// # use anyhow::Result;
-/// # struct MyGame;
-/// # impl gb_save_cli::GameCli for MyGame {
-/// #     fn detect_version(_bytes: &[u8]) -> Result<u16> { Ok(1) }
-/// #     fn patch(bytes: Vec<u8>, _target: u16, _dev_type: u8) -> Result<Vec<u8>> { Ok(bytes) }
-/// # }
-/// # fn main() -> Result<()> {
-/// gb_save_cli::run::<MyGame>()
-/// # }
-/// ```
-pub fn run<G: GameCli>() -> Result<()> {
-    run_with_args::<G, _, _>(std::env::args_os())
+fn render_version_human(version: u16, description: Option<&str>) -> String {
+    match description {
+        Some(description) => format!("{version} ({description})"),
+        None => version.to_string(),
+    }
 }
 
-/// Runs the CLI using the provided argument iterator.
-///
-/// This is primarily useful for tests and embedding.
-///
-/// # Example
-/// ```no_run
-/// # use anyhow::Result;
-/// # struct MyGame;
-/// # impl gb_save_cli::GameCli for MyGame {
-/// #     fn detect_version(_bytes: &[u8]) -> Result<u16> { Ok(1) }
-/// #     fn patch(bytes: Vec<u8>, _target: u16, _dev_type: u8) -> Result<Vec<u8>> { Ok(bytes) }
-/// # }
-/// # fn main() -> Result<()> {
-/// let args = ["gb-save-patcher", "version", "path/to/save.sav"];
-/// gb_save_cli::run_with_args::<MyGame, _, _>(args);
-/// # Ok(())
-/// # }
-/// ```
-pub fn run_with_args<G: GameCli, I, T>(args: I) -> Result<()>
-where
-    I: IntoIterator<Item = T>,
-    T: Into<OsString> + Clone,
-{
-    let cli = Cli::parse_from(args);
-
-    match cli.command {
-        Command::Version { path } => {
-            let bytes =
-                fs::read(&path).with_context(|| format!("read input: {}", path.display()))?;
-            let version = G::detect_version(&bytes)?;
-
-            match cli.format {
-                OutputFormat::Human => println!("{version}"),
-                OutputFormat::Json => {
-                    let mut obj = serde_json::Map::new();
-                    obj.insert("ok".to_string(), serde_json::Value::Bool(true));
-                    obj.insert(
-                        "version".to_string(),
-                        serde_json::Value::Number(version.into()),
-                    );
-                    println!("{}", serde_json::Value::Object(obj));
-                }
-            }
-        }
-        Command::Patch {
-            input,
-            output,
-            target,
-            dev_type,
-        } => {
-            let bytes =
-                fs::read(&input).with_context(|| format!("read input: {}", input.display()))?;
+fn supported_versions<G: GameCli>() -> RangeInclusive<u16> {
+    G::min_supported_version()..=G::max_supported_version()
+}
 
-            let outcome = G::patch_with_log(bytes, target, dev_type);
+fn render_save_info_human(
+    info: &SaveInfo,
+    description: Option<&str>,
+    supported: &RangeInclusive<u16>,
+) -> Vec<String> {
+    let checksum = match info.checksum_ok {
+        Some(true) => "ok",
+        Some(false) => "mismatch",
+        None => "not checked",
+    };
 
-            match cli.format {
-                OutputFormat::Human => {
-                    print_logs_human(&outcome.logs, cli.quiet, cli.verbose, cli.color);
-                    if let Some(error) = &outcome.error {
-                        anyhow::bail!(error.clone());
-                    }
-                }
-                OutputFormat::Json => {
-                    print_outcome_json(&outcome);
-                    if let Some(error) = &outcome.error {
-                        anyhow::bail!(error.clone());
-                    }
-                }
-            }
+    let mut lines = vec![
+        format!(
+            "{:<10}{}",
+            "version",
+            render_version_human(info.version, description)
+        ),
+        format!("{:<10}{} bytes", "size", info.size_bytes),
+        format!("{:<10}{checksum}", "checksum"),
+        format!(
+            "{:<10}versions {}-{}",
+            "supports",
+            supported.start(),
+            supported.end()
+        ),
+    ];
+    for (i, note) in info.notes.iter().enumerate() {
+        let label = if i == 0 { "notes" } else { "" };
+        lines.push(format!("{label:<10}{note}"));
+    }
+    lines
+}
 
-            let patched = outcome
-                .bytes
-                .with_context(|| "patch outcome did not include output bytes")?;
+fn save_info_json(
+    info: &SaveInfo,
+    description: Option<&str>,
+    supported: &RangeInclusive<u16>,
+) -> serde_json::Value {
+    serde_json::json!({
+        "ok": true,
+        "version": info.version,
+        "version_description": description,
+        "size_bytes": info.size_bytes,
+        "checksum_ok": info.checksum_ok,
+        "notes": info.notes,
+        "min_supported_version": supported.start(),
+        "max_supported_version": supported.end(),
+    })
+}
 
-            fs::write(&output, patched)
-                .with_context(|| format!("write output: {}", output.display()))?;
+fn print_patch_list_human(
+    migrations: &[MigrationInfo],
+    fixes: &[FixInfo],
+    supported: &RangeInclusive<u16>,
+) {
+    println!(
+        "Supported versions: {}-{}",
+        supported.start(),
+        supported.end()
+    );
+    println!("Migrations:");
+    if migrations.is_empty() {
+        println!("  (none)");
+    } else {
+        let id_width = migrations.iter().map(|m| m.id.len()).max().unwrap_or(0);
+        for m in migrations {
+            println!(
+                "  {id:<id_width$}  {from:>5} -> {to:<5}  {description}",
+                id = m.id,
+                from = m.from_version,
+                to = m.to_version,
+                description = m.description
+            );
         }
     }
 
-    Ok(())
+    println!("Fixes:");
+    if fixes.is_empty() {
+        println!("  (none)");
+    } else {
+        let id_width = fixes.iter().map(|f| f.id.len()).max().unwrap_or(0);
+        for f in fixes {
+            println!(
+                "  {id:<id_width$}  dev_type={dev_type:<3}  {description}",
+                id = f.id,
+                dev_type = f.dev_type,
+                description = f.description
+            );
+        }
+    }
 }
 
-#[cfg(test)]
-mod tests {
+fn patch_list_json(
+    migrations: &[MigrationInfo],
+    fixes: &[FixInfo],
+    supported: &RangeInclusive<u16>,
+) -> serde_json::Value {
+    let migrations = migrations
+        .iter()
+        .map(|m| {
+            serde_json::json!({
+                "id": m.id,
+                "from_version": m.from_version,
+                "to_version": m.to_version,
+                "description": m.description,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let fixes = fixes
+        .iter()
+        .map(|f| {
+            serde_json::json!({
+                "id": f.id,
+                "dev_type": f.dev_type,
+                "description": f.description,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::json!({
+        "ok": true,
+        "min_supported_version": supported.start(),
+        "max_supported_version": supported.end(),
+        "migrations": migrations,
+        "fixes": fixes,
+    })
+}
+
+fn render_diff_human(
+    before: &[u8],
+    after: &[u8],
+    changes: &[(Address, u8, u8)],
+    context: usize,
+) -> Vec<String> {
+    let common = before.len().min(after.len());
+
+    // Merge each change's context window into non-overlapping, sorted ranges.
+    let mut windows: Vec<std::ops::Range<usize>> = Vec::new();
+    for &(address, _, _) in changes {
+        let at = address.as_usize();
+        let end = at.saturating_add(context).saturating_add(1).min(common);
+        let window = at.saturating_sub(context)..end;
+        match windows.last_mut() {
+            Some(last) if window.start <= last.end => last.end = last.end.max(window.end),
+            _ => windows.push(window),
+        }
+    }
+
+    let mut lines = Vec::new();
+    for (index, window) in windows.into_iter().enumerate() {
+        if index > 0 && context > 0 {
+            lines.push("--".to_string());
+        }
+
+        for i in window {
+            if before[i] == after[i] {
+                lines.push(format!("0x{i:04X}: 0x{:02X}", before[i]));
+            } else {
+                lines.push(format!(
+                    "0x{i:04X}: 0x{:02X} -> 0x{:02X}",
+                    before[i], after[i]
+                ));
+            }
+        }
+    }
+
+    if before.len() != after.len() {
+        lines.push(format!(
+            "length differs: before={} after={}",
+            before.len(),
+            after.len()
+        ));
+    }
+
+    lines
+}
+
+fn diff_json(
+    before_len: usize,
+    after_len: usize,
+    changes: &[(Address, u8, u8)],
+) -> serde_json::Value {
+    let changes = changes
+        .iter()
+        .map(|&(address, before, after)| {
+            serde_json::json!({
+                "address": address.0,
+                "before": before,
+                "after": after,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::json!({
+        "ok": true,
+        "before_len": before_len,
+        "after_len": after_len,
+        "changes": changes,
+    })
+}
+
+/// Path argument that selects stdin (for inputs) or stdout (for outputs).
+const STDIO_PATH: &str = "-";
+
+fn is_stdio(path: &Path) -> bool {
+    path.as_os_str() == STDIO_PATH
+}
+
+fn read_input(path: &Path) -> Result<Vec<u8>> {
+    if !is_stdio(path) {
+        return read_input_from(path, std::io::empty(), false);
+    }
+
+    let stdin = std::io::stdin();
+    let is_terminal = stdin.is_terminal();
+    read_input_from(path, stdin.lock(), is_terminal)
+}
+
+fn read_input_from(path: &Path, mut stdin: impl Read, stdin_is_terminal: bool) -> Result<Vec<u8>> {
+    if !is_stdio(path) {
+        return fs::read(path).with_context(|| format!("read input: {}", path.display()));
+    }
+
+    if stdin_is_terminal {
+        return Err(SaveError::InvalidSaveState {
+            reason: "stdin is a terminal; pipe a save file when using `-` as the input".to_string(),
+        }
+        .into());
+    }
+
+    let mut bytes = Vec::new();
+    stdin.read_to_end(&mut bytes).context("read input: stdin")?;
+    Ok(bytes)
+}
+
+fn write_output(path: &Path, bytes: &[u8]) -> Result<()> {
+    write_output_to(path, bytes, std::io::stdout().lock())
+}
+
+fn write_output_to(path: &Path, bytes: &[u8], mut stdout: impl Write) -> Result<()> {
+    if !is_stdio(path) {
+        return fs::write(path, bytes).with_context(|| format!("write output: {}", path.display()));
+    }
+
+    stdout.write_all(bytes).context("write output: stdout")?;
+    stdout.flush().context("write output: stdout")?;
+    Ok(())
+}
+
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+
+    // Iterative wildcard match; backtracks to the most recent `*` on mismatch.
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+fn batch_inputs(dir: &Path, pattern: &str) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("read input dir: {}", dir.display()))? {
+        let entry = entry.with_context(|| format!("read input dir: {}", dir.display()))?;
+        let path = entry.path();
+        let matches = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| matches_pattern(pattern, name));
+        if matches && path.is_file() {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+fn run_batch_patch<G: GameCli>(cli: &Cli, args: &BatchPatchArgs) -> Result<()> {
+    let inputs = batch_inputs(&args.input_dir, &args.pattern)?;
+    fs::create_dir_all(&args.output_dir)
+        .with_context(|| format!("create output dir: {}", args.output_dir.display()))?;
+
+    let mut log = CliLogSink::new(cli, false);
+    let mut results = Vec::new();
+    let mut failed = 0usize;
+    let mut first_failure = None;
+    for input in &inputs {
+        let name = input.file_name().unwrap_or_default();
+        let output = args.output_dir.join(name);
+
+        // File IO failures keep their `io::Error` so they decide the exit code like a patch error.
+        let mut io_failure: Option<anyhow::Error> = None;
+        let mut outcome = match fs::read(input) {
+            Ok(bytes) => log.patch::<G>(bytes, args.target, args.dev_type),
+            Err(e) => {
+                let err = anyhow::Error::new(e).context("read input");
+                let outcome = PatchOutcome {
+                    ok: false,
+                    bytes: None,
+                    error: Some(format!("{err:#}")),
+                    save_error: None,
+                    logs: Vec::new(),
+                    applied_patch_ids: Vec::new(),
+                };
+                io_failure = Some(err);
+                outcome
+            }
+        };
+        if let Some(bytes) = &outcome.bytes {
+            if let Err(e) = fs::write(&output, bytes) {
+                let err = anyhow::Error::new(e).context("write output");
+                outcome.ok = false;
+                outcome.error = Some(format!("{err:#}"));
+                io_failure = Some(err);
+            }
+        }
+
+        match cli.format {
+            OutputFormat::Human => {
+                let (logs, _) = truncate_logs(&outcome.logs, cli.max_log_entries);
+                print_logs_human(&logs, cli.quiet, cli.verbose, cli.color);
+                print_applied_patches_human(&outcome, cli.quiet, cli.verbose);
+                match &outcome.error {
+                    Some(error) => eprintln!("{}: {error}", input.display()),
+                    None if !cli.quiet => println!("{} -> {}", input.display(), output.display()),
+                    None => {}
+                }
+            }
+            OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Toml => {
+                let mut obj = outcome_json(&outcome, cli.max_log_entries);
+                obj.insert(
+                    "input".to_string(),
+                    serde_json::Value::String(input.display().to_string()),
+                );
+                if outcome.ok {
+                    obj.insert(
+                        "output".to_string(),
+                        serde_json::Value::String(output.display().to_string()),
+                    );
+                }
+                if cli.format == OutputFormat::Ndjson {
+                    obj.remove("logs");
+                    obj.insert(
+                        "type".to_string(),
+                        serde_json::Value::String("file".to_string()),
+                    );
+                    write_json_line(&mut io::stdout(), &serde_json::Value::Object(obj));
+                } else {
+                    results.push(serde_json::Value::Object(obj));
+                }
+            }
+        }
+
+        if !outcome.ok {
+            failed += 1;
+            first_failure.get_or_insert_with(|| {
+                io_failure.unwrap_or_else(|| {
+                    outcome_error(
+                        outcome.error.as_deref().unwrap_or("patch failed"),
+                        outcome.save_error.clone(),
+                    )
+                })
+            });
+            if args.fail_fast {
+                break;
+            }
+        }
+    }
+
+    match cli.format {
+        OutputFormat::Human => {}
+        OutputFormat::Json | OutputFormat::Toml => {
+            print_structured(cli.format, serde_json::json!({ "results": results }), false)?;
+        }
+        OutputFormat::Ndjson => {
+            let mut obj = serde_json::Map::new();
+            obj.insert("ok".to_string(), serde_json::Value::Bool(failed == 0));
+            obj.insert(
+                "total".to_string(),
+                serde_json::Value::Number(inputs.len().into()),
+            );
+            obj.insert(
+                "failed".to_string(),
+                serde_json::Value::Number(failed.into()),
+            );
+            print_outcome_ndjson(obj, &mut io::stdout());
+        }
+    }
+
+    if let Some(err) = first_failure {
+        // The first failure's error stays in the chain so it decides the exit code.
+        return Err(err.context(format!(
+            "{failed} of {} files failed to patch",
+            inputs.len()
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "watch")]
+fn check_watch_args(cli: &Cli, args: &PatchArgs) -> Result<()> {
+    if cli.format != OutputFormat::Human {
+        anyhow::bail!("--watch only supports --format human");
+    }
+    if is_stdio(&args.input) {
+        anyhow::bail!("--watch requires a file input, not stdin");
+    }
+
+    let output = match (&args.output, &args.output_gz, &args.output_dir) {
+        (Some(path), _, _) | (_, Some(path), _) => path.clone(),
+        (_, _, Some(dir)) => dir.join(args.input.file_name().unwrap_or_default()),
+        _ => return Ok(()),
+    };
+    let same_file = output == args.input
+        || matches!(
+            (fs::canonicalize(&output), fs::canonicalize(&args.input)),
+            (Ok(a), Ok(b)) if a == b
+        );
+    if same_file {
+        anyhow::bail!("--watch cannot write its output over the watched input");
+    }
+    Ok(())
+}
+
+#[cfg(feature = "watch")]
+fn watch_timestamp() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600 % 24,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+#[cfg(feature = "watch")]
+fn run_watched_patch<G: GameCli>(cli: &Cli, args: &PatchArgs) {
+    match run_patch::<G>(cli, args) {
+        Ok(()) => eprintln!("[{}] patched {}", watch_timestamp(), args.input.display()),
+        Err(e) => eprintln!("[{}] patch failed: {e:#}", watch_timestamp()),
+    }
+}
+
+/// Patches once, then again after every change to the input until the process is interrupted.
+#[cfg(feature = "watch")]
+fn run_watch<G: GameCli>(cli: &Cli, args: &PatchArgs) -> Result<()> {
+    use notify::{EventKind, RecursiveMode, Watcher};
+
+    check_watch_args(cli, args)?;
+
+    // Watch the parent directory so editors that replace the file on save are still seen.
+    let dir = match args.input.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).context("start file watcher")?;
+    watcher
+        .watch(dir, RecursiveMode::NonRecursive)
+        .with_context(|| format!("watch input dir: {}", dir.display()))?;
+
+    eprintln!("watching {} (Ctrl-C to stop)", args.input.display());
+    run_watched_patch::<G>(cli, args);
+
+    let name = args.input.file_name();
+    for event in &rx {
+        let event = event.context("watch input")?;
+        let changed = matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+            && event.paths.iter().any(|path| path.file_name() == name);
+        if !changed {
+            continue;
+        }
+
+        // A single save usually produces a burst of events; patch once per burst.
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        while rx.try_recv().is_ok() {}
+        run_watched_patch::<G>(cli, args);
+    }
+    Ok(())
+}
+
+fn default_config_path() -> Option<PathBuf> {
+    let config_home = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+    };
+    Some(config_home.join("gb-save-patcher").join("config.toml"))
+}
+
+fn load_config(path: &Path) -> Result<Config> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("read config: {}", path.display()))?;
+    toml::from_str(&text).with_context(|| format!("parse config: {}", path.display()))
+}
+
+fn apply_config(cli: &mut Cli, config: &Config, matches: &ArgMatches) {
+    let from_command_line = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+
+    if let (Some(quiet), false) = (config.quiet, from_command_line("quiet")) {
+        cli.quiet = quiet;
+    }
+    if let (Some(verbose), false) = (config.verbose, from_command_line("verbose")) {
+        cli.verbose = verbose;
+    }
+    if let (Some(format), false) = (config.format, from_command_line("format")) {
+        cli.format = format;
+    }
+    if let (Some(color), false) = (config.color, from_command_line("color")) {
+        cli.color = color;
+    }
+}
+
+fn parse_env_flag(name: &str, value: &str) -> Result<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Ok(true),
+        "" | "0" | "false" | "no" | "off" => Ok(false),
+        _ => anyhow::bail!("invalid {name}: {value:?} (expected 1 or 0)"),
+    }
+}
+
+fn parse_env_enum<E: ValueEnum>(name: &str, value: &str) -> Result<E> {
+    E::from_str(value.trim(), true).map_err(|e| anyhow::anyhow!("invalid {name}: {e}"))
+}
+
+/// Applies `GB_SAVE_*` environment overrides for flags not given on the command line.
+fn apply_env(
+    cli: &mut Cli,
+    matches: &ArgMatches,
+    var: impl Fn(&str) -> Option<String>,
+) -> Result<()> {
+    let from_command_line = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+
+    if let (Some(value), false) = (var("GB_SAVE_QUIET"), from_command_line("quiet")) {
+        cli.quiet = parse_env_flag("GB_SAVE_QUIET", &value)?;
+    }
+    if let (Some(value), false) = (var("GB_SAVE_VERBOSE"), from_command_line("verbose")) {
+        cli.verbose = value
+            .trim()
+            .parse()
+            .with_context(|| format!("invalid GB_SAVE_VERBOSE: {value:?}"))?;
+    }
+    if let (Some(value), false) = (var("GB_SAVE_FORMAT"), from_command_line("format")) {
+        cli.format = parse_env_enum("GB_SAVE_FORMAT", &value)?;
+    }
+    if let (Some(value), false) = (var("GB_SAVE_COLOR"), from_command_line("color")) {
+        cli.color = parse_env_enum("GB_SAVE_COLOR", &value)?;
+    }
+    Ok(())
+}
+
+/// Forces [`ColorMode::Never`] for machine-readable formats, warning if color was requested.
+fn disable_color_for_structured_output(cli: &mut Cli) {
+    if cli.format == OutputFormat::Human {
+        return;
+    }
+    if cli.color == ColorMode::Always {
+        eprintln!("warning: --color always has no effect with a non-human --format");
+    }
+    cli.color = ColorMode::Never;
+}
+
+fn parse_cli<I, T>(args: I) -> Result<Cli>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    parse_cli_with(args, default_config_path(), |name| std::env::var(name).ok())
+}
+
+/// Like [`parse_cli`], but takes the default config path and the environment lookup as
+/// parameters so tests do not depend on the user's config file or `GB_SAVE_*` variables.
+fn parse_cli_with<I, T>(
+    args: I,
+    default_config: Option<PathBuf>,
+    var: impl Fn(&str) -> Option<String>,
+) -> Result<Cli>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    let matches = Cli::command()
+        .try_get_matches_from(args)
+        .map_err(usage_error)?;
+    let mut cli = Cli::from_arg_matches(&matches).map_err(usage_error)?;
+
+    // These commands ignore the global flags, so a broken config or environment must not block
+    // them (`config example` is how users fix a broken config).
+    if matches!(
+        cli.command,
+        Command::Config { .. } | Command::Completions { .. }
+    ) {
+        return Ok(cli);
+    }
+
+    let config = match &cli.config {
+        Some(path) => Some(load_config(path)?),
+        None => default_config
+            .filter(|path| path.is_file())
+            .and_then(|path| match load_config(&path) {
+                Ok(config) => Some(config),
+                Err(e) => {
+                    eprintln!("warning: ignoring config file: {e:#}");
+                    None
+                }
+            }),
+    };
+    if let Some(config) = config {
+        apply_config(&mut cli, &config, &matches);
+    }
+    apply_env(&mut cli, &matches, var)?;
+    disable_color_for_structured_output(&mut cli);
+
+    Ok(cli)
+}
+
+/// Exits for `--help` and `--version`, and otherwise returns the usage error so it maps to its
+/// own exit code.
+fn usage_error(e: clap::Error) -> anyhow::Error {
+    if !e.use_stderr() {
+        e.exit();
+    }
+    e.into()
+}
+
+fn write_completions(shell: clap_complete::Shell, out: &mut impl Write) {
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, out);
+}
+
+fn default_backup_path(output: &Path) -> PathBuf {
+    let mut path = output.as_os_str().to_owned();
+    path.push(".bak");
+    PathBuf::from(path)
+}
+
+fn resolve_dev_type<G: GameCli>(args: &PatchArgs) -> Result<u8> {
+    let Some(id) = &args.patch_id else {
+        return Ok(args.dev_type.unwrap_or(0));
+    };
+
+    G::resolve_patch_id(id).ok_or_else(|| {
+        SaveError::UnknownPatchId {
+            id: id.clone(),
+            known: G::list_fixes()
+                .into_iter()
+                .map(|f| f.id.to_string())
+                .collect(),
+        }
+        .into()
+    })
+}
+
+/// A `--target` outside the game's supported version range; maps to exit code 4.
+///
+/// Reports only the range bounds, unlike [`SaveError::VersionNotSupported`], which lists every
+/// supported version.
+#[derive(Debug)]
+struct UnsupportedTargetVersion {
+    target: u16,
+    supported: RangeInclusive<u16>,
+}
+
+impl std::fmt::Display for UnsupportedTargetVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unsupported target version {}: this game supports versions {}-{}",
+            self.target,
+            self.supported.start(),
+            self.supported.end()
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedTargetVersion {}
+
+fn check_target_version<G: GameCli>(target: u16) -> Result<()> {
+    let supported = supported_versions::<G>();
+    if !supported.contains(&target) {
+        return Err(UnsupportedTargetVersion { target, supported }.into());
+    }
+    Ok(())
+}
+
+fn gzip_bytes(bytes: &[u8]) -> Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(bytes).context("compress output")?;
+    encoder.finish().context("compress output")
+}
+
+/// Returns `--out` or `--out-gz`, or the input's file name under `--output-dir` (creating the directory unless
+/// this is a dry run).
+fn resolve_output_path(args: &PatchArgs) -> Result<PathBuf> {
+    if let Some(path) = &args.output_gz {
+        return Ok(path.clone());
+    }
+    let Some(dir) = &args.output_dir else {
+        return args
+            .output
+            .clone()
+            .context("either --out or --output-dir is required");
+    };
+
+    let name = match args.input.file_name() {
+        Some(name) if !is_stdio(&args.input) => name,
+        _ => anyhow::bail!("--output-dir requires a file input; use --out with stdin"),
+    };
+    if !args.dry_run {
+        fs::create_dir_all(dir).with_context(|| format!("create output dir: {}", dir.display()))?;
+    }
+    Ok(dir.join(name))
+}
+
+fn run_patch<G: GameCli>(cli: &Cli, args: &PatchArgs) -> Result<()> {
+    check_target_version::<G>(args.target)?;
+    let dev_type = resolve_dev_type::<G>(args)?;
+    if args.paranoid && is_stdio(&args.input) {
+        anyhow::bail!("--paranoid is not supported when reading input from stdin");
+    }
+
+    let output = resolve_output_path(args)?;
+
+    let bytes = read_input(&args.input)?;
+    if args.paranoid {
+        G::verify_checksum(&bytes)
+            .context("input checksum verification failed; refusing to patch")?;
+    }
+    let original = args.backup.then(|| bytes.clone());
+
+    // Keep stdout clean for the patched bytes when piping.
+    let to_stderr = is_stdio(&output);
+    let mut log = CliLogSink::new(cli, to_stderr);
+    let outcome = log.patch::<G>(bytes, args.target, dev_type);
+
+    let checksum = match &outcome.bytes {
+        Some(bytes) if args.verify_checksum || args.paranoid => Some(G::verify_checksum(bytes)),
+        _ => None,
+    };
+    let checksum_valid = checksum.as_ref().map(Result::is_ok);
+    let compressed = match (&args.output_gz, &outcome.bytes) {
+        (Some(_), Some(bytes)) => Some(gzip_bytes(bytes)?),
+        _ => None,
+    };
+
+    let backup_path = match &original {
+        Some(original) if outcome.ok && checksum_valid != Some(false) && !args.dry_run => {
+            let path = match &args.backup_path {
+                Some(path) => path.clone(),
+                None if is_stdio(&output) => {
+                    anyhow::bail!("--backup-path is required when writing output to stdout")
+                }
+                None => default_backup_path(&output),
+            };
+            fs::write(&path, original)
+                .with_context(|| format!("write backup: {}", path.display()))?;
+            Some(path)
+        }
+        _ => None,
+    };
+
+    match cli.format {
+        OutputFormat::Human => {
+            // Dry runs always show info logs so the migration plan is visible.
+            let verbose = if args.dry_run {
+                cli.verbose.max(1)
+            } else {
+                cli.verbose
+            };
+            let (logs, _) = truncate_logs(&outcome.logs, cli.max_log_entries);
+            print_logs_human(&logs, cli.quiet, verbose, cli.color);
+            print_applied_patches_human(&outcome, cli.quiet, verbose);
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Toml => {
+            let mut obj = outcome_json(&outcome, cli.max_log_entries);
+            if args.dry_run {
+                obj.insert("dry_run".to_string(), serde_json::Value::Bool(true));
+                if let Some(bytes) = &outcome.bytes {
+                    obj.insert(
+                        "would_write_bytes".to_string(),
+                        serde_json::Value::Number(bytes.len().into()),
+                    );
+                }
+            }
+            if let Some(valid) = checksum_valid {
+                obj.insert("checksum_valid".to_string(), serde_json::Value::Bool(valid));
+            }
+            if let Some(compressed) = &compressed {
+                obj.insert(
+                    "output_compressed_bytes".to_string(),
+                    serde_json::Value::Number(compressed.len().into()),
+                );
+            }
+            if let Some(path) = &backup_path {
+                obj.insert(
+                    "backup_path".to_string(),
+                    serde_json::Value::String(path.display().to_string()),
+                );
+            }
+            if cli.format == OutputFormat::Ndjson {
+                log.print_result(obj);
+            } else {
+                print_structured(cli.format, serde_json::Value::Object(obj), to_stderr)?;
+            }
+        }
+    }
+
+    if let Some(error) = &outcome.error {
+        return Err(outcome_error(error, outcome.save_error.clone()));
+    }
+
+    if let Some(Err(e)) = checksum {
+        let message = if args.paranoid {
+            "output checksum verification failed; output not written"
+        } else {
+            "checksum verification failed; output not written"
+        };
+        return Err(e.context(message));
+    }
+
+    let patched = outcome
+        .bytes
+        .with_context(|| "patch outcome did not include output bytes")?;
+
+    if args.dry_run {
+        if cli.format == OutputFormat::Human && !cli.quiet {
+            println!(
+                "dry run complete, would write {} bytes to {}",
+                patched.len(),
+                output.display()
+            );
+        }
+        return Ok(());
+    }
+
+    match &compressed {
+        Some(compressed) => {
+            write_output(&output, compressed)?;
+            if cli.format == OutputFormat::Human && !cli.quiet {
+                eprintln!(
+                    "wrote {} bytes ({} compressed) to {}",
+                    patched.len(),
+                    compressed.len(),
+                    output.display()
+                );
+            }
+        }
+        None => write_output(&output, &patched)?,
+    }
+
+    Ok(())
+}
+
+/// Maps a [`SaveError`] to the CLI's process exit code.
+///
+/// | Code | Meaning |
+/// |------|---------|
+/// | 1 | generic error |
+/// | 2 | file IO error (see [`exit_code`]) |
+/// | 3 | save validation error |
+/// | 4 | unsupported version |
+/// | 5 | unknown patch |
+/// | 64 | command-line usage error (see [`exit_code`]) |
+///
+/// `PatchFailed` uses the code of its source error and `MultipleErrors` the code of its first
+/// error.
+///
+/// # Example
+/// ```
+/// use gb_save_core::SaveError;
+///
+/// let err = SaveError::UnknownFixPatch { dev_type: 9 };
+/// assert_eq!(gb_save_cli::exit_code_for_error(&err), 5);
+/// ```
+pub fn exit_code_for_error(e: &SaveError) -> i32 {
+    match e {
+        SaveError::InvalidSaveState { .. }
+        | SaveError::ChecksumMismatch { .. }
+        | SaveError::ChecksumMismatch32 { .. }
+        | SaveError::CorruptedSave { .. } => 3,
+        SaveError::UnsupportedMigrationDirection { .. }
+        | SaveError::MissingMigrationStep { .. }
+        | SaveError::VersionNotSupported { .. } => 4,
+        SaveError::UnknownFixPatch { .. } | SaveError::UnknownPatchId { .. } => 5,
+        SaveError::PatchFailed { source, .. } => exit_code_for_error(source),
+        SaveError::MultipleErrors(errors) => errors.first().map_or(1, exit_code_for_error),
+        _ => 1,
+    }
+}
+
+/// Maps an error returned by [`run_with_args`] to the CLI's process exit code.
+///
+/// The first [`SaveError`], [`std::io::Error`], out-of-range `--target` or command-line usage
+/// error in the error chain decides the code; see [`exit_code_for_error`] for the full table.
+/// Anything else maps to 1.
+pub fn exit_code(err: &anyhow::Error) -> i32 {
+    for cause in err.chain() {
+        if let Some(e) = cause.downcast_ref::<SaveError>() {
+            return exit_code_for_error(e);
+        }
+        if cause.is::<UnsupportedTargetVersion>() {
+            return 4;
+        }
+        if cause.is::<std::io::Error>() {
+            return 2;
+        }
+        if cause.is::<clap::Error>() {
+            // `EX_USAGE` from sysexits.h; clap's own default (2) would collide with file IO errors.
+            return 64;
+        }
+    }
+    1
+}
+
+/// Returns a copy of the first [`SaveError`] in `err`'s chain.
+fn find_save_error(err: &anyhow::Error) -> Option<SaveError> {
+    err.chain()
+        .find_map(|cause| cause.downcast_ref::<SaveError>())
+        .cloned()
+}
+
+/// Rebuilds the error of a failed [`PatchOutcome`], keeping its typed [`SaveError`] (and so its
+/// exit code) when the game reported one.
+fn outcome_error(message: &str, save_error: Option<SaveError>) -> anyhow::Error {
+    match save_error {
+        Some(e) if e.to_string() == message => e.into(),
+        Some(e) => anyhow::Error::new(e).context(message.to_string()),
+        None => anyhow::anyhow!("{message}"),
+    }
+}
+
+/// Runs the CLI and converts any error into a process exit code via [`exit_code`].
+///
+/// Errors are printed to stderr the same way returning them from `main` would.
+///
+/// # Example
+/// ```no_run
+/// # use anyhow::Result;
+/// # struct MyGame;
+/// # impl gb_save_cli::GameCli for MyGame {
+/// #     fn detect_version(_bytes: &[u8]) -> Result<u16> { Ok(1) }
+/// #     fn patch(bytes: Vec<u8>, _target: u16, _dev_type: u8) -> Result<Vec<u8>> { Ok(bytes) }
+/// # }
+/// fn main() -> std::process::ExitCode {
+///     gb_save_cli::run_and_report::<MyGame>()
+/// }
+/// ```
+pub fn run_and_report<G: GameCli>() -> std::process::ExitCode {
+    match run::<G>() {
+        Ok(()) => std::process::ExitCode::SUCCESS,
+        Err(err) => {
+            match err.downcast_ref::<clap::Error>() {
+                // Clap renders its own usage hint.
+                Some(e) => {
+                    let _ = e.print();
+                }
+                None => eprintln!("Error: {err:?}"),
+            }
+            let code = u8::try_from(exit_code(&err)).unwrap_or(1);
+            std::process::ExitCode::from(code)
+        }
+    }
+}
+
+/// Runs the CLI using the game-specific implementation `G`.
+///
+/// # Example
+/// ```no_run
+/// # use anyhow::Result;
+/// # struct MyGame;
+/// # impl gb_save_cli::GameCli for MyGame {
+/// #     fn detect_version(_bytes: &[u8]) -> Result<u16> { Ok(1) }
+/// #     fn patch(bytes: Vec<u8>, _target: u16, _dev_type: u8) -> Result<Vec<u8>> { Ok(bytes) }
+/// # }
+/// # fn main() -> Result<()> {
+/// gb_save_cli::run::<MyGame>()
+/// # }
+/// ```
+pub fn run<G: GameCli>() -> Result<()> {
+    run_with_args::<G, _, _>(std::env::args_os())
+}
+
+/// Runs the CLI using the provided argument iterator.
+///
+/// This is primarily useful for tests and embedding.
+///
+/// # Example
+/// ```no_run
+/// # use anyhow::Result;
+/// # struct MyGame;
+/// # impl gb_save_cli::GameCli for MyGame {
+/// #     fn detect_version(_bytes: &[u8]) -> Result<u16> { Ok(1) }
+/// #     fn patch(bytes: Vec<u8>, _target: u16, _dev_type: u8) -> Result<Vec<u8>> { Ok(bytes) }
+/// # }
+/// # fn main() -> Result<()> {
+/// let args = ["gb-save-patcher", "version", "path/to/save.sav"];
+/// gb_save_cli::run_with_args::<MyGame, _, _>(args);
+/// # Ok(())
+/// # }
+/// ```
+pub fn run_with_args<G: GameCli, I, T>(args: I) -> Result<()>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    run_cli::<G>(parse_cli(args)?)
+}
+
+fn run_cli<G: GameCli>(cli: Cli) -> Result<()> {
+    match &cli.command {
+        Command::Version { path } => {
+            let bytes = read_input(path)?;
+            let version = G::detect_version(&bytes)?;
+            let description = G::describe_version(version);
+
+            match cli.format {
+                OutputFormat::Human => {
+                    println!("{}", render_version_human(version, description.as_deref()));
+                }
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Toml => {
+                    let mut obj = serde_json::Map::new();
+                    obj.insert("ok".to_string(), serde_json::Value::Bool(true));
+                    obj.insert(
+                        "version".to_string(),
+                        serde_json::Value::Number(version.into()),
+                    );
+                    if let Some(description) = description {
+                        obj.insert(
+                            "description".to_string(),
+                            serde_json::Value::String(description),
+                        );
+                    }
+                    print_structured(cli.format, serde_json::Value::Object(obj), false)?;
+                }
+            }
+        }
+        Command::Validate { path } => {
+            let bytes = read_input(path)?;
+            let result = G::validate(&bytes).and_then(|()| G::detect_version(&bytes));
+
+            match cli.format {
+                OutputFormat::Human => {
+                    let version = result?;
+                    println!("valid (version {version})");
+                }
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Toml => {
+                    let mut obj = serde_json::Map::new();
+                    obj.insert("ok".to_string(), serde_json::Value::Bool(result.is_ok()));
+                    match &result {
+                        Ok(version) => {
+                            obj.insert(
+                                "version".to_string(),
+                                serde_json::Value::Number((*version).into()),
+                            );
+                        }
+                        Err(e) => {
+                            obj.insert(
+                                "error".to_string(),
+                                serde_json::Value::String(e.to_string()),
+                            );
+                        }
+                    }
+                    print_structured(cli.format, serde_json::Value::Object(obj), false)?;
+                    result?;
+                }
+            }
+        }
+        Command::Info { path } => {
+            let bytes = read_input(path)?;
+            let info = G::describe_save(&bytes)?;
+            let description = G::describe_version(info.version);
+
+            match cli.format {
+                OutputFormat::Human => {
+                    let supported = supported_versions::<G>();
+                    for line in render_save_info_human(&info, description.as_deref(), &supported) {
+                        println!("{line}");
+                    }
+                }
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Toml => {
+                    let supported = supported_versions::<G>();
+                    let value = save_info_json(&info, description.as_deref(), &supported);
+                    print_structured(cli.format, value, false)?;
+                }
+            }
+        }
+        Command::ListPatches => {
+            let migrations = G::list_migrations();
+            let fixes = G::list_fixes();
+            let supported = supported_versions::<G>();
+
+            match cli.format {
+                OutputFormat::Human => print_patch_list_human(&migrations, &fixes, &supported),
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Toml => {
+                    let value = patch_list_json(&migrations, &fixes, &supported);
+                    print_structured(cli.format, value, false)?;
+                }
+            }
+        }
+        Command::Diff {
+            before,
+            after,
+            context,
+        } => {
+            let before_bytes =
+                fs::read(before).with_context(|| format!("read before: {}", before.display()))?;
+            let after_bytes =
+                fs::read(after).with_context(|| format!("read after: {}", after.display()))?;
+            let before_len = before_bytes.len();
+            let after_len = after_bytes.len();
+            let before_save = SaveBinary::new(before_bytes);
+            let after_save = SaveBinary::new(after_bytes);
+            let changes = before_save.diff(&after_save);
+
+            match cli.format {
+                OutputFormat::Human => {
+                    for line in render_diff_human(
+                        before_save.as_bytes(),
+                        after_save.as_bytes(),
+                        &changes,
+                        *context,
+                    ) {
+                        println!("{line}");
+                    }
+                }
+                OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Toml => {
+                    let value = diff_json(before_len, after_len, &changes);
+                    print_structured(cli.format, value, false)?;
+                }
+            }
+        }
+        #[cfg(feature = "watch")]
+        Command::Patch(args) if args.watch => run_watch::<G>(&cli, args)?,
+        Command::Patch(args) => run_patch::<G>(&cli, args)?,
+        Command::BatchPatch(args) => run_batch_patch::<G>(&cli, args)?,
+        Command::Completions { shell } => write_completions(*shell, &mut std::io::stdout()),
+        Command::Config {
+            action: ConfigCommand::Example,
+        } => print!("{CONFIG_EXAMPLE}"),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
     use super::*;
 
+    /// [`parse_cli`] without the user's config file or `GB_SAVE_*` variables.
+    fn parse_isolated<I, T>(args: I) -> Result<Cli>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        parse_cli_with(args, None, |_| None)
+    }
+
+    /// [`run_with_args`] without the user's config file or `GB_SAVE_*` variables.
+    fn run_isolated<G: GameCli, I, T>(args: I) -> Result<()>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        run_cli::<G>(parse_isolated(args)?)
+    }
+
+    struct StubGame;
+
+    impl GameCli for StubGame {
+        fn detect_version(bytes: &[u8]) -> Result<u16> {
+            match bytes.first() {
+                Some(&version) if version != 0 => Ok(u16::from(version)),
+                _ => Err(SaveError::InvalidSaveState {
+                    reason: "unrecognized save".to_string(),
+                }
+                .into()),
+            }
+        }
+
+        fn patch(bytes: Vec<u8>, target: u16, dev_type: u8) -> Result<Vec<u8>> {
+            let version = Self::detect_version(&bytes)?;
+            if dev_type > 1 {
+                return Err(SaveError::UnknownFixPatch { dev_type }.into());
+            }
+            if target < version {
+                return Err(SaveError::UnsupportedMigrationDirection {
+                    current_version: version,
+                    target_version: target,
+                }
+                .into());
+            }
+            Ok(bytes)
+        }
+
+        fn patch_with_log(bytes: Vec<u8>, target: u16, dev_type: u8) -> PatchOutcome {
+            let logs = vec![PatchLogEntry::warning("stub", "patching")];
+            match Self::patch(bytes, target, dev_type) {
+                Ok(bytes) => PatchOutcome {
+                    ok: true,
+                    bytes: Some(bytes),
+                    error: None,
+                    save_error: None,
+                    logs,
+                    applied_patch_ids: vec!["stub_patch".into()],
+                },
+                Err(e) => PatchOutcome {
+                    ok: false,
+                    bytes: None,
+                    error: Some(e.to_string()),
+                    save_error: find_save_error(&e),
+                    logs,
+                    applied_patch_ids: Vec::new(),
+                },
+            }
+        }
+
+        fn list_fixes() -> Vec<FixInfo> {
+            vec![FixInfo {
+                id: "fix_bag",
+                dev_type: 1,
+                description: "",
+            }]
+        }
+
+        fn resolve_patch_id(id: &str) -> Option<u8> {
+            (id == "fix_bag").then_some(1)
+        }
+
+        fn max_supported_version() -> u16 {
+            9
+        }
+
+        fn verify_checksum(bytes: &[u8]) -> Result<()> {
+            match bytes.last() {
+                Some(0xFF) => anyhow::bail!("bad checksum"),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("gb-save-cli-test-{}-{name}", std::process::id()))
+    }
+
+    fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+        let path = temp_path(name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn patch_list_json_includes_migrations_and_fixes() {
+        let migrations = vec![MigrationInfo {
+            id: "m1_to_2",
+            from_version: 1,
+            to_version: 2,
+            description: "first",
+        }];
+        let fixes = vec![FixInfo {
+            id: "fix1",
+            dev_type: 1,
+            description: "",
+        }];
+
+        let value = patch_list_json(&migrations, &fixes, &(1..=2));
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "ok": true,
+                "min_supported_version": 1,
+                "max_supported_version": 2,
+                "migrations": [
+                    {"id": "m1_to_2", "from_version": 1, "to_version": 2, "description": "first"}
+                ],
+                "fixes": [
+                    {"id": "fix1", "dev_type": 1, "description": ""}
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn list_patches_runs_with_default_empty_lists() {
+        run_isolated::<StubGame, _, _>(["gb-save-patcher", "--format", "json", "list-patches"])
+            .unwrap();
+    }
+
+    #[test]
+    fn diff_human_prints_one_line_per_change() {
+        let before = [0x00, 0x01, 0x02, 0x03];
+        let after = [0x00, 0xFF, 0x02, 0x04];
+        let changes = SaveBinary::new(before.to_vec()).diff(&SaveBinary::new(after.to_vec()));
+        assert_eq!(
+            render_diff_human(&before, &after, &changes, 0),
+            vec!["0x0001: 0x01 -> 0xFF", "0x0003: 0x03 -> 0x04"]
+        );
+    }
+
+    #[test]
+    fn diff_human_includes_context_and_separators() {
+        let before = [0u8; 10];
+        let mut after = before;
+        after[1] = 1;
+        after[8] = 2;
+        let changes = SaveBinary::new(before.to_vec()).diff(&SaveBinary::new(after.to_vec()));
+        assert_eq!(
+            render_diff_human(&before, &after, &changes, 1),
+            vec![
+                "0x0000: 0x00",
+                "0x0001: 0x00 -> 0x01",
+                "0x0002: 0x00",
+                "--",
+                "0x0007: 0x00",
+                "0x0008: 0x00 -> 0x02",
+                "0x0009: 0x00",
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_human_clamps_huge_context() {
+        let before = [0x00, 0x01, 0x02];
+        let after = [0x00, 0xFF, 0x02];
+        let changes = SaveBinary::new(before.to_vec()).diff(&SaveBinary::new(after.to_vec()));
+        assert_eq!(
+            render_diff_human(&before, &after, &changes, usize::MAX),
+            vec!["0x0000: 0x00", "0x0001: 0x01 -> 0xFF", "0x0002: 0x02"]
+        );
+    }
+
+    #[test]
+    fn diff_json_lists_changes() {
+        let changes = vec![(Address(66), 0x00, 0xFF)];
+        assert_eq!(
+            diff_json(4, 4, &changes),
+            serde_json::json!({
+                "ok": true,
+                "before_len": 4,
+                "after_len": 4,
+                "changes": [{"address": 66, "before": 0, "after": 255}],
+            })
+        );
+    }
+
+    #[test]
+    fn patch_dry_run_does_not_write_output() {
+        let input = temp_file("dry-run-in.sav", &[1, 2, 3]);
+        let output = temp_path("dry-run-out.sav");
+        let _ = fs::remove_file(&output);
+
+        for format in ["human", "json", "toml"] {
+            run_isolated::<StubGame, _, OsString>([
+                "gb-save-patcher".into(),
+                "--format".into(),
+                format.into(),
+                "patch".into(),
+                "--in".into(),
+                input.clone().into_os_string(),
+                "--out".into(),
+                output.clone().into_os_string(),
+                "--target".into(),
+                "1".into(),
+                "--dry-run".into(),
+            ])
+            .unwrap();
+            assert!(!output.exists());
+        }
+        fs::remove_file(input).unwrap();
+    }
+
+    #[test]
+    fn patch_backup_copies_original_input() {
+        let path = temp_file("backup-in-place.sav", &[1, 2, 3]);
+        let backup = default_backup_path(&path);
+        let _ = fs::remove_file(&backup);
+
+        run_isolated::<StubGame, _, OsString>([
+            "gb-save-patcher".into(),
+            "patch".into(),
+            "--in".into(),
+            path.clone().into_os_string(),
+            "--out".into(),
+            path.clone().into_os_string(),
+            "--target".into(),
+            "1".into(),
+            "--backup".into(),
+        ])
+        .unwrap();
+
+        assert_eq!(fs::read(&backup).unwrap(), vec![1, 2, 3]);
+        fs::remove_file(path).unwrap();
+        fs::remove_file(backup).unwrap();
+    }
+
+    #[test]
+    fn patch_backup_path_overrides_default_location() {
+        let input = temp_file("backup-custom-in.sav", &[4, 5]);
+        let output = temp_path("backup-custom-out.sav");
+        let backup = temp_path("backup-custom.orig");
+
+        run_isolated::<StubGame, _, OsString>([
+            "gb-save-patcher".into(),
+            "--format".into(),
+            "json".into(),
+            "patch".into(),
+            "--in".into(),
+            input.clone().into_os_string(),
+            "--out".into(),
+            output.clone().into_os_string(),
+            "--target".into(),
+            "4".into(),
+            "--backup".into(),
+            "--backup-path".into(),
+            backup.clone().into_os_string(),
+        ])
+        .unwrap();
+
+        assert_eq!(fs::read(&backup).unwrap(), vec![4, 5]);
+        assert!(!default_backup_path(&output).exists());
+        for path in [input, output, backup] {
+            fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn patch_verify_checksum_blocks_output_on_mismatch() {
+        let input = temp_file("checksum-bad-in.sav", &[1, 0xFF]);
+        let output = temp_path("checksum-bad-out.sav");
+        let _ = fs::remove_file(&output);
+
+        for format in ["human", "json"] {
+            let err = run_isolated::<StubGame, _, OsString>([
+                "gb-save-patcher".into(),
+                "--format".into(),
+                format.into(),
+                "patch".into(),
+                "--in".into(),
+                input.clone().into_os_string(),
+                "--out".into(),
+                output.clone().into_os_string(),
+                "--target".into(),
+                "1".into(),
+                "--verify-checksum".into(),
+                "--backup".into(),
+            ])
+            .unwrap_err();
+            assert_eq!(
+                format!("{err:#}"),
+                "checksum verification failed; output not written: bad checksum"
+            );
+            assert!(!output.exists());
+            assert!(!default_backup_path(&output).exists());
+        }
+        fs::remove_file(input).unwrap();
+    }
+
+    #[test]
+    fn patch_verify_checksum_writes_valid_output() {
+        let input = temp_file("checksum-ok-in.sav", &[1, 2]);
+        let output = temp_path("checksum-ok-out.sav");
+
+        run_isolated::<StubGame, _, OsString>([
+            "gb-save-patcher".into(),
+            "--format".into(),
+            "json".into(),
+            "patch".into(),
+            "--in".into(),
+            input.clone().into_os_string(),
+            "--out".into(),
+            output.clone().into_os_string(),
+            "--target".into(),
+            "1".into(),
+            "--verify-checksum".into(),
+        ])
+        .unwrap();
+        assert_eq!(fs::read(&output).unwrap(), vec![1, 2]);
+        fs::remove_file(input).unwrap();
+        fs::remove_file(output).unwrap();
+    }
+
+    fn patch_args(extra: &[&str]) -> PatchArgs {
+        let args = [
+            "patch", "--in", "in.sav", "--out", "out.sav", "--target", "1",
+        ];
+        let cli = Cli::try_parse_from(
+            std::iter::once("gb-save-patcher")
+                .chain(args)
+                .chain(extra.iter().copied()),
+        )
+        .unwrap();
+        match cli.command {
+            Command::Patch(args) => args,
+            other => panic!("unexpected command: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn out_and_output_dir_are_mutually_exclusive() {
+        let err = Cli::try_parse_from([
+            "gb-save-patcher",
+            "patch",
+            "--in",
+            "in.sav",
+            "--out",
+            "out.sav",
+            "--output-dir",
+            "dir",
+            "--target",
+            "1",
+        ])
+        .unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+
+        let err = Cli::try_parse_from([
+            "gb-save-patcher",
+            "patch",
+            "--in",
+            "in.sav",
+            "--target",
+            "1",
+        ])
+        .unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn patch_output_dir_keeps_input_file_name() {
+        let input = temp_file("output-dir-in.sav", &[1, 2]);
+        let dir = temp_path("output-dir").join("nested");
+        run_isolated::<StubGame, _, OsString>([
+            "gb-save-patcher".into(),
+            "patch".into(),
+            "--in".into(),
+            input.clone().into_os_string(),
+            "--output-dir".into(),
+            dir.clone().into_os_string(),
+            "--target".into(),
+            "1".into(),
+        ])
+        .unwrap();
+
+        let output = dir.join(input.file_name().unwrap());
+        assert_eq!(fs::read(&output).unwrap(), vec![1, 2]);
+        fs::remove_file(input).unwrap();
+        fs::remove_dir_all(temp_path("output-dir")).unwrap();
+    }
+
+    #[test]
+    fn patch_out_gz_writes_compressed_output() {
+        let input = temp_file("out-gz-in.sav", &[1, 2, 3]);
+        let output = temp_path("out-gz-out.sav.gz");
+        for format in ["human", "json"] {
+            run_isolated::<StubGame, _, OsString>([
+                "gb-save-patcher".into(),
+                "--format".into(),
+                format.into(),
+                "patch".into(),
+                "--in".into(),
+                input.clone().into_os_string(),
+                "--out-gz".into(),
+                output.clone().into_os_string(),
+                "--target".into(),
+                "1".into(),
+                "--backup".into(),
+            ])
+            .unwrap();
+
+            let mut decoded = Vec::new();
+            flate2::read::GzDecoder::new(fs::File::open(&output).unwrap())
+                .read_to_end(&mut decoded)
+                .unwrap();
+            assert_eq!(decoded, vec![1, 2, 3]);
+            assert_eq!(
+                fs::read(default_backup_path(&output)).unwrap(),
+                vec![1, 2, 3]
+            );
+        }
+        fs::remove_file(input).unwrap();
+        fs::remove_file(default_backup_path(&output)).unwrap();
+        fs::remove_file(output).unwrap();
+    }
+
+    #[test]
+    fn out_gz_conflicts_with_out() {
+        let err = Cli::try_parse_from([
+            "gb-save-patcher",
+            "patch",
+            "--in",
+            "in.sav",
+            "--out",
+            "out.sav",
+            "--out-gz",
+            "out.sav.gz",
+            "--target",
+            "1",
+        ])
+        .unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn watch_rejects_structured_output_and_in_place_patching() {
+        let check = |args: &[&str]| {
+            let cli =
+                Cli::try_parse_from(std::iter::once("gb-save-patcher").chain(args.iter().copied()))
+                    .unwrap();
+            let Command::Patch(patch) = &cli.command else {
+                panic!("expected patch command");
+            };
+            assert!(patch.watch);
+            check_watch_args(&cli, patch)
+        };
+
+        let patch = [
+            "patch", "--in", "in.sav", "--out", "out.sav", "--target", "1", "--watch",
+        ];
+        assert!(check(&patch).is_ok());
+
+        let json = [&["--format", "json"][..], &patch[..]].concat();
+        assert_eq!(
+            check(&json).unwrap_err().to_string(),
+            "--watch only supports --format human"
+        );
+
+        let in_place = [
+            "patch", "--in", "in.sav", "--out", "in.sav", "--target", "1", "--watch",
+        ];
+        assert!(check(&in_place).is_err());
+
+        let stdin = [
+            "patch", "--in", "-", "--out", "out.sav", "--target", "1", "--watch",
+        ];
+        assert!(check(&stdin).is_err());
+    }
+
+    #[test]
+    fn patch_output_dir_rejects_stdin_input() {
+        let args = Cli::try_parse_from([
+            "gb-save-patcher",
+            "patch",
+            "--in",
+            "-",
+            "--output-dir",
+            "dir",
+            "--target",
+            "1",
+        ])
+        .unwrap();
+        let Command::Patch(args) = args.command else {
+            panic!("expected patch command");
+        };
+        assert!(resolve_output_path(&args).is_err());
+    }
+
+    #[test]
+    fn patch_id_resolves_to_dev_type() {
+        assert_eq!(resolve_dev_type::<StubGame>(&patch_args(&[])).unwrap(), 0);
+        assert_eq!(
+            resolve_dev_type::<StubGame>(&patch_args(&["--dev-type", "3"])).unwrap(),
+            3
+        );
+        assert_eq!(
+            resolve_dev_type::<StubGame>(&patch_args(&["--patch-id", "fix_bag"])).unwrap(),
+            1
+        );
+
+        let err = resolve_dev_type::<StubGame>(&patch_args(&["--patch-id", "nope"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown patch id: nope (known ids: fix_bag)"
+        );
+    }
+
+    #[test]
+    fn patch_rejects_target_outside_supported_range_before_reading() {
+        let err = run_isolated::<StubGame, _, OsString>([
+            "gb-save-patcher".into(),
+            "patch".into(),
+            "--in".into(),
+            temp_path("range-missing.sav").into_os_string(),
+            "--out".into(),
+            temp_path("range-out.sav").into_os_string(),
+            "--target".into(),
+            "10".into(),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unsupported target version 10: this game supports versions 0-9"
+        );
+        assert_eq!(exit_code(&err), 4);
+    }
+
+    #[test]
+    fn patch_id_conflicts_with_dev_type() {
+        let err = Cli::try_parse_from([
+            "gb-save-patcher",
+            "patch",
+            "--in",
+            "in.sav",
+            "--out",
+            "out.sav",
+            "--target",
+            "1",
+            "--dev-type",
+            "1",
+            "--patch-id",
+            "fix_bag",
+        ])
+        .unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn log_file_records_entries_even_when_quiet() {
+        let input = temp_file("log-file-in.sav", &[1, 2]);
+        let output = temp_path("log-file-out.sav");
+        let log = temp_path("log-file.ndjson");
+        let _ = fs::remove_file(&log);
+
+        for _ in 0..2 {
+            run_isolated::<StubGame, _, OsString>([
+                "gb-save-patcher".into(),
+                "--quiet".into(),
+                "--log-file".into(),
+                log.clone().into_os_string(),
+                "patch".into(),
+                "--in".into(),
+                input.clone().into_os_string(),
+                "--out".into(),
+                output.clone().into_os_string(),
+                "--target".into(),
+                "1".into(),
+            ])
+            .unwrap();
+        }
+
+        let line = r#"{"level":"warn","message":"patching","source":"stub"}"#;
+        assert_eq!(
+            fs::read_to_string(&log).unwrap(),
+            format!("{line}\n{line}\n")
+        );
+        for path in [input, output, log] {
+            fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn default_patch_with_sink_replays_logs() {
+        let mut sink = gb_save_core::VecPatchLogSink::new();
+        let outcome = StubGame::patch_with_sink(vec![1], 1, 0, &mut sink);
+        assert!(outcome.ok);
+        assert_eq!(sink.into_entries(), outcome.logs);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn patch_outcome_serde_round_trip() {
+        let outcome = PatchOutcome {
+            ok: true,
+            bytes: Some(vec![1, 2, 3]),
+            error: None,
+            save_error: None,
+            logs: vec![PatchLogEntry::warning("stub", "careful")],
+            applied_patch_ids: vec!["m1_to_2".into()],
+        };
+        let json = serde_json::to_string(&outcome).unwrap();
+        assert_eq!(
+            json,
+            r#"{"ok":true,"bytes_base64":"AQID","error":null,"logs":[{"level":"warn","source":"stub","message":"careful"}],"applied_patches":["m1_to_2"]}"#
+        );
+
+        let parsed: PatchOutcome = serde_json::from_str(&json).unwrap();
+        assert!(parsed.ok);
+        assert_eq!(parsed.bytes, outcome.bytes);
+        assert!(parsed.save_error.is_none());
+        assert_eq!(parsed.logs, outcome.logs);
+        assert_eq!(parsed.applied_patch_ids, outcome.applied_patch_ids);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn patch_outcome_serde_omits_missing_bytes() {
+        let outcome = PatchOutcome {
+            ok: false,
+            bytes: None,
+            error: Some("boom".to_string()),
+            save_error: None,
+            logs: Vec::new(),
+            applied_patch_ids: Vec::new(),
+        };
+        let json = serde_json::to_string(&outcome).unwrap();
+        assert_eq!(
+            json,
+            r#"{"ok":false,"error":"boom","logs":[],"applied_patches":[]}"#
+        );
+
+        let parsed: PatchOutcome = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.bytes, None);
+        assert_eq!(parsed.error.as_deref(), Some("boom"));
+    }
+
+    fn outcome_with_logs(ok: bool) -> PatchOutcome {
+        PatchOutcome {
+            ok,
+            bytes: ok.then(|| vec![1, 2]),
+            error: (!ok).then(|| "failed".to_string()),
+            save_error: None,
+            logs: vec![
+                PatchLogEntry::info("a", "one"),
+                PatchLogEntry::warning("b", "two"),
+                PatchLogEntry::error("c", "three"),
+            ],
+            applied_patch_ids: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn outcome_json_includes_applied_patches() {
+        let outcome = PatchOutcome {
+            applied_patch_ids: vec!["m1_to_2".into(), "m2_to_3".into()],
+            ..outcome_with_logs(true)
+        };
+        assert_eq!(
+            outcome_json(&outcome, None)["applied_patches"],
+            serde_json::json!(["m1_to_2", "m2_to_3"])
+        );
+    }
+
+    #[test]
+    fn max_log_entries_truncates_printed_logs_only() {
+        let outcome = outcome_with_logs(true);
+
+        let (logs, truncated) = truncate_logs(&outcome.logs, Some(1));
+        assert!(truncated);
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0], outcome.logs[0]);
+        assert_eq!(logs[1].level, PatchLogLevel::Warning);
+        assert_eq!(logs[1].message, "output truncated: 2 entries omitted");
+
+        let (logs, truncated) = truncate_logs(&outcome.logs, Some(3));
+        assert!(!truncated);
+        assert_eq!(logs.len(), 3);
+
+        let obj = outcome_json(&outcome, Some(0));
+        assert_eq!(obj["logs_truncated"], serde_json::json!(true));
+        assert_eq!(obj["logs"].as_array().unwrap().len(), 1);
+        assert!(!outcome_json(&outcome, None).contains_key("logs_truncated"));
+        assert_eq!(outcome.logs.len(), 3);
+    }
+
+    #[test]
+    fn outcome_toml_has_json_top_level_keys() {
+        let ok = serde_json::Value::Object(outcome_json(&outcome_with_logs(true), None));
+        let ok: toml::Table = toml::from_str(&outcome_toml(ok).unwrap()).unwrap();
+        for key in ["ok", "logs", "applied_patches", "bytes_len"] {
+            assert!(ok.contains_key(key), "missing {key}");
+        }
+        assert_eq!(ok["logs"].as_array().unwrap().len(), 3);
+
+        let failed = serde_json::Value::Object(outcome_json(&outcome_with_logs(false), None));
+        let failed: toml::Table = toml::from_str(&outcome_toml(failed).unwrap()).unwrap();
+        assert_eq!(failed["ok"].as_bool(), Some(false));
+        assert_eq!(failed["error"].as_str(), Some("failed"));
+    }
+
+    #[test]
+    fn outcome_toml_omits_null_fields() {
+        let info = SaveInfo {
+            version: 3,
+            size_bytes: 2,
+            checksum_ok: None,
+            notes: Vec::new(),
+        };
+        let value = save_info_json(&info, None, &(1..=3));
+        let table: toml::Table = toml::from_str(&outcome_toml(value).unwrap()).unwrap();
+        assert_eq!(table["version"].as_integer(), Some(3));
+        assert!(!table.contains_key("checksum_ok"));
+        assert!(!table.contains_key("version_description"));
+    }
+
+    #[test]
+    fn version_supports_toml_format() {
+        let path = temp_file("version-toml.sav", &[2, 0]);
+        run_isolated::<StubGame, _, OsString>([
+            "gb-save-patcher".into(),
+            "--format".into(),
+            "toml".into(),
+            "version".into(),
+            path.clone().into_os_string(),
+        ])
+        .unwrap();
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn filter_logs_by_level_keeps_everything_else() {
+        let outcome = outcome_with_logs(true);
+
+        let filtered = outcome.filter_logs_by_level(PatchLogLevel::Warning);
+        assert_eq!(
+            filtered
+                .logs
+                .iter()
+                .map(|e| e.source.as_ref())
+                .collect::<Vec<_>>(),
+            vec!["b", "c"]
+        );
+        assert!(filtered.ok);
+        assert_eq!(filtered.bytes, outcome.bytes);
+        assert_eq!(filtered.error, None);
+
+        let all = outcome.filter_logs_by_level(PatchLogLevel::Info);
+        assert_eq!(all.logs, outcome.logs);
+    }
+
+    #[test]
+    fn has_errors_checks_outcome_and_logs() {
+        let with_error_log = outcome_with_logs(true);
+        assert_eq!(with_error_log.logs_with_errors().len(), 1);
+        assert!(with_error_log.has_errors());
+
+        let clean = with_error_log.filter_logs_by_level(PatchLogLevel::Error);
+        assert!(clean.has_errors());
+        let clean = PatchOutcome {
+            logs: Vec::new(),
+            ..clean
+        };
+        assert!(clean.logs_with_errors().is_empty());
+        assert!(!clean.has_errors());
+
+        let failed = outcome_with_logs(false).filter_logs_by_level(PatchLogLevel::Info);
+        let failed = PatchOutcome {
+            logs: Vec::new(),
+            ..failed
+        };
+        assert!(failed.has_errors());
+    }
+
+    #[test]
+    fn default_patch_with_progress_delegates_to_patch_with_sink() {
+        let mut sink = gb_save_core::VecPatchLogSink::new();
+        let mut calls = 0;
+        let outcome = StubGame::patch_with_progress(vec![1, 2], 1, 0, &mut sink, |_, _| calls += 1);
+        assert!(outcome.ok);
+        assert_eq!(outcome.bytes, Some(vec![1, 2]));
+        assert_eq!(outcome.logs.len(), 1);
+        assert_eq!(sink.into_entries(), outcome.logs);
+        assert_eq!(calls, 0);
+    }
+
+    struct StreamingGame;
+
+    impl GameCli for StreamingGame {
+        fn detect_version(_bytes: &[u8]) -> Result<u16> {
+            Ok(1)
+        }
+
+        fn patch(bytes: Vec<u8>, _target: u16, _dev_type: u8) -> Result<Vec<u8>> {
+            Ok(bytes)
+        }
+
+        fn patch_with_progress(
+            bytes: Vec<u8>,
+            _target: u16,
+            _dev_type: u8,
+            log: &mut dyn PatchLogSink,
+            mut on_progress: impl FnMut(usize, usize),
+        ) -> PatchOutcome {
+            let logs = vec![
+                PatchLogEntry::info("m1_to_2", "step one"),
+                PatchLogEntry::info("m2_to_3", "step two"),
+            ];
+            for (done, entry) in logs.iter().enumerate() {
+                log.push(entry.clone());
+                on_progress(done + 1, logs.len());
+            }
+            PatchOutcome {
+                ok: true,
+                bytes: Some(bytes),
+                error: None,
+                save_error: None,
+                logs,
+                applied_patch_ids: vec!["m1_to_2".into(), "m2_to_3".into()],
+            }
+        }
+    }
+
+    #[test]
+    fn ndjson_streams_logs_and_progress_before_the_result() {
+        let out = Rc::new(RefCell::new(Vec::new()));
+        let mut sink = CliLogSink {
+            file: None,
+            ndjson: Some(out.clone()),
+        };
+        let outcome = sink.patch::<StreamingGame>(vec![1], 3, 0);
+        sink.print_result(outcome_json(&outcome, None));
+
+        let text = String::from_utf8(out.borrow().clone()).unwrap();
+        let lines: Vec<(String, String)> = text
+            .lines()
+            .map(|line| {
+                let value: serde_json::Value = serde_json::from_str(line).unwrap();
+                let detail = value
+                    .get("message")
+                    .or_else(|| value.get("done"))
+                    .map_or_else(String::new, ToString::to_string);
+                (value["type"].as_str().unwrap().to_string(), detail)
+            })
+            .collect();
+        let expected = [
+            ("log", "\"step one\""),
+            ("progress", "1"),
+            ("log", "\"step two\""),
+            ("progress", "2"),
+            ("result", ""),
+        ];
+        assert_eq!(
+            lines,
+            expected.map(|(kind, detail)| (kind.to_string(), detail.to_string()))
+        );
+    }
+
+    #[test]
+    fn ndjson_patch_writes_output_and_log_file() {
+        let input = temp_file("ndjson-in.sav", &[1, 2]);
+        let output = temp_path("ndjson-out.sav");
+        let log = temp_path("ndjson-log.ndjson");
+        let _ = fs::remove_file(&log);
+
+        run_isolated::<StubGame, _, OsString>([
+            "gb-save-patcher".into(),
+            "--format".into(),
+            "ndjson".into(),
+            "--log-file".into(),
+            log.clone().into_os_string(),
+            "patch".into(),
+            "--in".into(),
+            input.clone().into_os_string(),
+            "--out".into(),
+            output.clone().into_os_string(),
+            "--target".into(),
+            "1".into(),
+        ])
+        .unwrap();
+
+        assert_eq!(fs::read(&output).unwrap(), vec![1, 2]);
+        assert_eq!(fs::read_to_string(&log).unwrap().lines().count(), 1);
+        for path in [input, output, log] {
+            fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn unopenable_log_file_is_not_fatal() {
+        let dir = temp_path("log-file-missing-dir");
+        assert!(open_log_file(Some(&dir.join("log.ndjson"))).is_none());
+    }
+
+    fn run_paranoid(input: &Path, output: &Path) -> Result<()> {
+        run_isolated::<StubGame, _, OsString>([
+            "gb-save-patcher".into(),
+            "patch".into(),
+            "--in".into(),
+            input.into(),
+            "--out".into(),
+            output.into(),
+            "--target".into(),
+            "1".into(),
+            "--paranoid".into(),
+        ])
+    }
+
+    #[test]
+    fn paranoid_rejects_bad_input_checksum() {
+        let input = temp_file("paranoid-bad-in.sav", &[1, 0xFF]);
+        let output = temp_path("paranoid-bad-in-out.sav");
+
+        let err = run_paranoid(&input, &output).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "input checksum verification failed; refusing to patch"
+        );
+        assert!(!output.exists());
+        fs::remove_file(input).unwrap();
+    }
+
+    #[test]
+    fn paranoid_verifies_output_and_rejects_stdin() {
+        let input = temp_file("paranoid-ok-in.sav", &[1, 2]);
+        let output = temp_path("paranoid-ok-out.sav");
+
+        run_paranoid(&input, &output).unwrap();
+        assert_eq!(fs::read(&output).unwrap(), vec![1, 2]);
+
+        let err = run_paranoid(Path::new("-"), &output).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "--paranoid is not supported when reading input from stdin"
+        );
+        fs::remove_file(input).unwrap();
+        fs::remove_file(output).unwrap();
+    }
+
+    #[test]
+    fn dash_input_reads_from_stdin_handle() {
+        let path = temp_file("stdin-standin.sav", &[9, 8, 7]);
+        let stdin = fs::File::open(&path).unwrap();
+        let bytes = read_input_from(Path::new("-"), stdin, false).unwrap();
+        assert_eq!(bytes, vec![9, 8, 7]);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn dash_input_rejects_terminal_stdin() {
+        let err = read_input_from(Path::new("-"), std::io::empty(), true).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<SaveError>(),
+            Some(SaveError::InvalidSaveState { .. })
+        ));
+    }
+
+    #[test]
+    fn dash_output_writes_to_stdout_handle() {
+        let path = temp_path("stdout-standin.sav");
+        let stdout = fs::File::create(&path).unwrap();
+        write_output_to(Path::new("-"), &[1, 2], stdout).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), vec![1, 2]);
+        assert!(!Path::new("-").exists());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn pattern_matches_wildcards() {
+        assert!(matches_pattern("*.sav", "red.sav"));
+        assert!(matches_pattern("*.sav", ".sav"));
+        assert!(!matches_pattern("*.sav", "red.srm"));
+        assert!(matches_pattern("save?.s*", "save1.srm"));
+        assert!(!matches_pattern("save?.sav", "save12.sav"));
+        assert!(matches_pattern("*a*b", "xaxxab"));
+    }
+
+    fn batch_dirs(name: &str) -> (PathBuf, PathBuf) {
+        let input_dir = temp_path(&format!("{name}-in"));
+        let output_dir = temp_path(&format!("{name}-out"));
+        let _ = fs::remove_dir_all(&input_dir);
+        let _ = fs::remove_dir_all(&output_dir);
+        fs::create_dir_all(&input_dir).unwrap();
+        fs::write(input_dir.join("a.sav"), [1]).unwrap();
+        fs::write(input_dir.join("b.sav"), [0]).unwrap();
+        fs::write(input_dir.join("c.sav"), [2]).unwrap();
+        fs::write(input_dir.join("notes.txt"), [1]).unwrap();
+        (input_dir, output_dir)
+    }
+
+    fn run_batch(input_dir: &Path, output_dir: &Path, extra: &[&str]) -> Result<()> {
+        let mut args: Vec<OsString> = vec![
+            "gb-save-patcher".into(),
+            "--format".into(),
+            "json".into(),
+            "batch-patch".into(),
+            "--input-dir".into(),
+            input_dir.into(),
+            "--output-dir".into(),
+            output_dir.into(),
+            "--target".into(),
+            "2".into(),
+        ];
+        args.extend(extra.iter().map(OsString::from));
+        run_isolated::<StubGame, _, OsString>(args)
+    }
+
+    #[test]
+    fn batch_patch_continues_past_failures_by_default() {
+        let (input_dir, output_dir) = batch_dirs("batch-continue");
+
+        let err = run_batch(&input_dir, &output_dir, &[]).unwrap_err();
+        assert_eq!(err.to_string(), "1 of 3 files failed to patch");
+        assert!(output_dir.join("a.sav").exists());
+        assert!(!output_dir.join("b.sav").exists());
+        assert!(output_dir.join("c.sav").exists());
+        assert!(!output_dir.join("notes.txt").exists());
+
+        fs::remove_dir_all(input_dir).unwrap();
+        fs::remove_dir_all(output_dir).unwrap();
+    }
+
+    #[test]
+    fn batch_patch_fail_fast_stops_at_first_failure() {
+        let (input_dir, output_dir) = batch_dirs("batch-fail-fast");
+
+        let err = run_batch(&input_dir, &output_dir, &["--fail-fast"]).unwrap_err();
+        assert_eq!(err.to_string(), "1 of 3 files failed to patch");
+        assert!(output_dir.join("a.sav").exists());
+        assert!(!output_dir.join("c.sav").exists());
+
+        fs::remove_dir_all(input_dir).unwrap();
+        fs::remove_dir_all(output_dir).unwrap();
+    }
+
+    #[test]
+    fn batch_patch_honors_pattern() {
+        let (input_dir, output_dir) = batch_dirs("batch-pattern");
+
+        run_batch(&input_dir, &output_dir, &["--pattern", "*.txt"]).unwrap();
+        assert!(output_dir.join("notes.txt").exists());
+        assert!(!output_dir.join("a.sav").exists());
+
+        fs::remove_dir_all(input_dir).unwrap();
+        fs::remove_dir_all(output_dir).unwrap();
+    }
+
+    #[test]
+    fn bash_completions_are_generated() {
+        let mut out = Vec::new();
+        write_completions(clap_complete::Shell::Bash, &mut out);
+        let script = String::from_utf8(out).unwrap();
+        assert!(!script.is_empty());
+        assert!(script.contains("gb-save-patcher"));
+        assert!(script.contains("batch-patch"));
+    }
+
+    #[test]
+    fn config_example_parses() {
+        let config: Config = toml::from_str(CONFIG_EXAMPLE).unwrap();
+        assert_eq!(
+            config,
+            Config {
+                quiet: Some(false),
+                verbose: Some(0),
+                format: Some(OutputFormat::Human),
+                color: Some(ColorMode::Auto),
+            }
+        );
+    }
+
+    #[test]
+    fn config_quiet_suppresses_warnings() {
+        let config = temp_file("config-quiet.toml", b"quiet = true\n");
+
+        let cli = parse_isolated([
+            OsString::from("gb-save-patcher"),
+            "--config".into(),
+            config.clone().into_os_string(),
+            "list-patches".into(),
+        ])
+        .unwrap();
+        assert!(cli.quiet);
+        assert!(!should_print(
+            PatchLogLevel::Warning,
+            cli.quiet,
+            cli.verbose
+        ));
+        assert!(should_print(PatchLogLevel::Error, cli.quiet, cli.verbose));
+        fs::remove_file(config).unwrap();
+    }
+
+    #[test]
+    fn command_line_flags_override_config() {
+        let config = temp_file(
+            "config-override.toml",
+            b"format = \"json\"\ncolor = \"never\"\n",
+        );
+
+        let cli = parse_isolated([
+            OsString::from("gb-save-patcher"),
+            "list-patches".into(),
+            "--config".into(),
+            config.clone().into_os_string(),
+            "--format".into(),
+            "human".into(),
+        ])
+        .unwrap();
+        assert_eq!(cli.format, OutputFormat::Human);
+        assert_eq!(cli.color, ColorMode::Never);
+        fs::remove_file(config).unwrap();
+    }
+
+    #[test]
+    fn broken_default_config_is_only_a_warning() {
+        let config = temp_file("config-broken-default.toml", b"format = \"xml\"\n");
+
+        let cli = parse_cli_with(
+            ["gb-save-patcher", "list-patches"],
+            Some(config.clone()),
+            |_| None,
+        )
+        .unwrap();
+        assert_eq!(cli.format, OutputFormat::Human);
+
+        let explicit = parse_isolated([
+            OsString::from("gb-save-patcher"),
+            "--config".into(),
+            config.clone().into_os_string(),
+            "list-patches".into(),
+        ]);
+        assert!(explicit.is_err());
+        fs::remove_file(config).unwrap();
+    }
+
+    #[test]
+    fn config_and_completions_skip_config_and_env() {
+        let missing = temp_path("config-skip-missing.toml");
+        let broken_env = |_: &str| Some("xml".to_string());
+        for command in [&["config", "example"][..], &["completions", "bash"]] {
+            let mut args = vec![
+                OsString::from("gb-save-patcher"),
+                "--config".into(),
+                missing.clone().into_os_string(),
+            ];
+            args.extend(command.iter().map(OsString::from));
+            assert!(
+                parse_cli_with(args, None, broken_env).is_ok(),
+                "{command:?}"
+            );
+        }
+    }
+
+    fn env_cli<'a>(args: &[&str], vars: &'a [(&'a str, &'a str)]) -> Result<Cli> {
+        parse_cli_with(args, None, |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (*value).to_string())
+        })
+    }
+
+    #[test]
+    fn env_vars_fill_in_unset_flags() {
+        let vars = [
+            ("GB_SAVE_FORMAT", "json"),
+            ("GB_SAVE_COLOR", "never"),
+            ("GB_SAVE_QUIET", "1"),
+            ("GB_SAVE_VERBOSE", "2"),
+        ];
+        let cli = env_cli(&["gb-save-patcher", "list-patches"], &vars).unwrap();
+        assert_eq!(cli.format, OutputFormat::Json);
+        assert_eq!(cli.color, ColorMode::Never);
+        assert!(cli.quiet);
+        assert_eq!(cli.verbose, 2);
+    }
+
+    #[test]
+    fn command_line_flags_override_env_vars() {
+        let vars = [("GB_SAVE_FORMAT", "json"), ("GB_SAVE_VERBOSE", "2")];
+        let cli = env_cli(
+            &["gb-save-patcher", "--format", "toml", "-v", "list-patches"],
+            &vars,
+        )
+        .unwrap();
+        assert_eq!(cli.format, OutputFormat::Toml);
+        assert_eq!(cli.verbose, 1);
+    }
+
+    #[test]
+    fn invalid_env_vars_are_rejected() {
+        let err = env_cli(
+            &["gb-save-patcher", "list-patches"],
+            &[("GB_SAVE_QUIET", "maybe")],
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("invalid GB_SAVE_QUIET"));
+        assert!(env_cli(
+            &["gb-save-patcher", "list-patches"],
+            &[("GB_SAVE_FORMAT", "xml")]
+        )
+        .is_err());
+        assert!(env_cli(
+            &["gb-save-patcher", "list-patches"],
+            &[("GB_SAVE_FORMAT", "JSON")]
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn structured_formats_never_use_color() {
+        for format in ["json", "ndjson", "toml"] {
+            let cli = parse_isolated([
+                "gb-save-patcher",
+                "--format",
+                format,
+                "--color",
+                "always",
+                "list-patches",
+            ])
+            .unwrap();
+            assert_eq!(cli.color, ColorMode::Never);
+        }
+
+        let cli = parse_isolated(["gb-save-patcher", "--color", "always", "list-patches"]).unwrap();
+        assert_eq!(cli.color, ColorMode::Always);
+    }
+
+    #[test]
+    fn json_output_has_no_ansi_codes() {
+        let cli = parse_isolated([
+            "gb-save-patcher",
+            "--format",
+            "json",
+            "--color",
+            "always",
+            "list-patches",
+        ])
+        .unwrap();
+        assert!(!should_color(cli.color));
+
+        let json =
+            serde_json::Value::Object(outcome_json(&outcome_with_logs(false), None)).to_string();
+        assert!(!json.contains('\x1b'));
+    }
+
+    #[test]
+    fn config_rejects_unknown_keys() {
+        let config = temp_file("config-unknown.toml", b"colour = \"never\"\n");
+        let err = load_config(&config).unwrap_err();
+        assert!(err.to_string().starts_with("parse config:"));
+        fs::remove_file(config).unwrap();
+    }
+
+    #[test]
+    fn save_info_renders_table_and_json() {
+        let info = SaveInfo {
+            version: 3,
+            size_bytes: 32768,
+            checksum_ok: Some(false),
+            notes: vec!["box 2 is empty".to_string(), "clock unset".to_string()],
+        };
+        assert_eq!(
+            render_save_info_human(&info, Some("final"), &(1..=3)),
+            vec![
+                "version   3 (final)",
+                "size      32768 bytes",
+                "checksum  mismatch",
+                "supports  versions 1-3",
+                "notes     box 2 is empty",
+                "          clock unset",
+            ]
+        );
+        assert_eq!(
+            save_info_json(&info, None, &(0..=u16::MAX)),
+            serde_json::json!({
+                "ok": true,
+                "version": 3,
+                "version_description": null,
+                "size_bytes": 32768,
+                "checksum_ok": false,
+                "notes": ["box 2 is empty", "clock unset"],
+                "min_supported_version": 0,
+                "max_supported_version": 65535,
+            })
+        );
+    }
+
+    #[test]
+    fn default_describe_save_reports_version_and_size() {
+        assert_eq!(
+            StubGame::describe_save(&[5, 0, 0]).unwrap(),
+            SaveInfo {
+                version: 5,
+                size_bytes: 3,
+                checksum_ok: None,
+                notes: Vec::new(),
+            }
+        );
+        assert!(StubGame::describe_save(&[0]).is_err());
+    }
+
+    #[test]
+    fn exit_codes_follow_error_kind() {
+        let cases = [
+            (
+                SaveError::NotImplemented {
+                    feature: "x".to_string(),
+                },
+                1,
+            ),
+            (
+                SaveError::InvalidSaveState {
+                    reason: "x".to_string(),
+                },
+                3,
+            ),
+            (
+                SaveError::ChecksumMismatch {
+                    which: "main",
+                    stored: 1,
+                    calculated: 2,
+                },
+                3,
+            ),
+            (
+                SaveError::ChecksumMismatch32 {
+                    which: "adler32",
+                    stored: 1,
+                    calculated: 2,
+                },
+                3,
+            ),
+            (
+                SaveError::UnsupportedMigrationDirection {
+                    current_version: 2,
+                    target_version: 1,
+                },
+                4,
+            ),
+            (
+                SaveError::MissingMigrationStep {
+                    from_version: 1,
+                    target_version: 3,
+                },
+                4,
+            ),
+            (
+                SaveError::VersionNotSupported {
+                    version: 9,
+                    supported: vec![1, 2, 3],
+                },
+                4,
+            ),
+            (
+                SaveError::CorruptedSave {
+                    offset: Address(0x10),
+                    reason: "bad magic".to_string(),
+                },
+                3,
+            ),
+            (SaveError::UnknownFixPatch { dev_type: 7 }, 5),
+            (
+                SaveError::MultipleErrors(vec![
+                    SaveError::UnknownFixPatch { dev_type: 7 },
+                    SaveError::InvalidBitIndex { bit: 9 },
+                ]),
+                5,
+            ),
+            (SaveError::MultipleErrors(Vec::new()), 1),
+            (
+                SaveError::PatchFailed {
+                    id: "m1_to_2",
+                    source: Box::new(SaveError::MissingMigrationStep {
+                        from_version: 1,
+                        target_version: 3,
+                    }),
+                },
+                4,
+            ),
+        ];
+        for (err, code) in cases {
+            assert_eq!(exit_code_for_error(&err), code, "{err}");
+        }
+    }
+
+    #[test]
+    fn run_errors_map_to_exit_codes() {
+        let missing = temp_path("exit-code-missing.sav");
+        let err = run_isolated::<StubGame, _, OsString>([
+            "gb-save-patcher".into(),
+            "version".into(),
+            missing.into_os_string(),
+        ])
+        .unwrap_err();
+        assert_eq!(exit_code(&err), 2);
+
+        let bad = temp_file("exit-code-bad.sav", &[0]);
+        let err = run_isolated::<StubGame, _, OsString>([
+            "gb-save-patcher".into(),
+            "version".into(),
+            bad.clone().into_os_string(),
+        ])
+        .unwrap_err();
+        assert_eq!(exit_code(&err), 3);
+        fs::remove_file(bad).unwrap();
+
+        let input_dir = temp_path("exit-code-no-dir");
+        let err = run_isolated::<StubGame, _, OsString>([
+            "gb-save-patcher".into(),
+            "batch-patch".into(),
+            "--input-dir".into(),
+            input_dir.into_os_string(),
+            "--output-dir".into(),
+            temp_path("exit-code-out").into_os_string(),
+            "--target".into(),
+            "1".into(),
+        ])
+        .unwrap_err();
+        assert_eq!(exit_code(&err), 2);
+
+        assert_eq!(exit_code(&anyhow::anyhow!("something else")), 1);
+    }
+
+    fn run_patch_args(name: &str, extra: &[&str]) -> Result<()> {
+        let input = temp_file(&format!("{name}-in.sav"), &[3, 0]);
+        let mut args: Vec<OsString> = vec![
+            "gb-save-patcher".into(),
+            "patch".into(),
+            "--in".into(),
+            input.clone().into_os_string(),
+            "--out".into(),
+            temp_path(&format!("{name}-out.sav")).into_os_string(),
+        ];
+        args.extend(extra.iter().map(OsString::from));
+        let result = run_isolated::<StubGame, _, OsString>(args);
+        fs::remove_file(input).unwrap();
+        result
+    }
+
+    #[test]
+    fn patch_errors_map_to_exit_codes() {
+        let err = run_patch_args("exit-downgrade", &["--target", "2"]).unwrap_err();
+        assert_eq!(err.to_string(), "unsupported migration direction: 3 -> 2");
+        assert_eq!(exit_code(&err), 4);
+
+        let err =
+            run_patch_args("exit-dev-type", &["--target", "3", "--dev-type", "7"]).unwrap_err();
+        assert_eq!(err.to_string(), "unknown fix patch: dev_type=7");
+        assert_eq!(exit_code(&err), 5);
+
+        let err =
+            run_patch_args("exit-patch-id", &["--target", "3", "--patch-id", "nope"]).unwrap_err();
+        assert_eq!(exit_code(&err), 5);
+
+        let err = run_patch_args("exit-usage", &["--target", "3", "--no-such-flag"]).unwrap_err();
+        assert_eq!(exit_code(&err), 64);
+    }
+
+    #[test]
+    fn batch_patch_exit_code_follows_first_failure() {
+        let (input_dir, output_dir) = batch_dirs("batch-exit-code");
+        let err = run_batch(&input_dir, &output_dir, &["--dev-type", "7"]).unwrap_err();
+        assert_eq!(err.to_string(), "3 of 3 files failed to patch");
+        assert_eq!(exit_code(&err), 5);
+
+        fs::remove_dir_all(input_dir).unwrap();
+        let _ = fs::remove_dir_all(output_dir);
+    }
+
+    #[test]
+    fn batch_patch_exit_code_is_io_for_write_failure() {
+        let (input_dir, output_dir) = batch_dirs("batch-exit-io");
+        fs::create_dir_all(output_dir.join("a.sav")).unwrap();
+        let err = run_batch(&input_dir, &output_dir, &[]).unwrap_err();
+        assert_eq!(err.to_string(), "2 of 3 files failed to patch");
+        assert_eq!(exit_code(&err), 2);
+
+        fs::remove_dir_all(input_dir).unwrap();
+        let _ = fs::remove_dir_all(output_dir);
+    }
+
+    #[test]
+    fn default_validate_accepts_any_parseable_save() {
+        assert!(StubGame::validate(&[1]).is_ok());
+        assert!(StubGame::validate(&[9, 0xFF, 0xFF]).is_ok());
+        assert!(StubGame::validate(&[0]).is_err());
+        assert!(StubGame::validate(&[]).is_err());
+    }
+
+    #[test]
+    fn validate_succeeds_for_recognized_save() {
+        let path = temp_file("validate-ok.sav", &[3, 0, 0]);
+        for format in ["human", "json", "toml"] {
+            run_isolated::<StubGame, _, OsString>([
+                "gb-save-patcher".into(),
+                "--format".into(),
+                format.into(),
+                "validate".into(),
+                path.clone().into_os_string(),
+            ])
+            .unwrap();
+        }
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn validate_fails_for_unrecognized_save() {
+        let path = temp_file("validate-bad.sav", &[0, 0, 0]);
+        for format in ["human", "json", "toml"] {
+            let err = run_isolated::<StubGame, _, OsString>([
+                "gb-save-patcher".into(),
+                "--format".into(),
+                format.into(),
+                "validate".into(),
+                path.clone().into_os_string(),
+            ])
+            .unwrap_err();
+            assert_eq!(err.to_string(), "invalid save state: unrecognized save");
+        }
+        fs::remove_file(path).unwrap();
+    }
+
     #[test]
     fn quiet_only_prints_errors() {
         assert!(!should_print(PatchLogLevel::Info, true, 0));
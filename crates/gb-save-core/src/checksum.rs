@@ -1,4 +1,4 @@
-use crate::{AddressRange, SaveBinary, SaveError, SaveResult};
+use crate::{Address, AddressRange, SaveBinary, SaveError, SaveResult, Size};
 
 /// Calculates the additive checksum of a save byte range.
 ///
@@ -7,6 +7,18 @@ use crate::{AddressRange, SaveBinary, SaveError, SaveResult};
 /// # Errors
 /// Returns an error if `range` is invalid or falls outside the save buffer.
 pub fn calculate_additive_u16_checksum(save: &SaveBinary, range: AddressRange) -> SaveResult<u16> {
+    calculate_additive_u16_checksum_seeded(save, range, 0)
+}
+
+/// Like [`calculate_additive_u16_checksum`], but starts the sum at `seed` instead of 0.
+///
+/// # Errors
+/// Returns an error if `range` is invalid or falls outside the save buffer.
+pub fn calculate_additive_u16_checksum_seeded(
+    save: &SaveBinary,
+    range: AddressRange,
+    seed: u16,
+) -> SaveResult<u16> {
     if range.start.0 >= range.end.0 {
         return Err(SaveError::InvalidAddressRange { range });
     }
@@ -14,7 +26,245 @@ pub fn calculate_additive_u16_checksum(save: &SaveBinary, range: AddressRange) -
     let bytes = save.slice(range)?;
     Ok(bytes
         .iter()
-        .fold(0u16, |acc, b| acc.wrapping_add(*b as u16)))
+        .fold(seed, |acc, b| acc.wrapping_add(*b as u16)))
+}
+
+/// Calculates one checksum per `bank_size` bank of the save.
+///
+/// The save is split into `save.len() / bank_size` whole banks; trailing bytes that do not fill a
+/// bank are ignored.
+///
+/// # Errors
+/// Returns the first error produced by `checksum_fn`.
+///
+/// # Panics
+/// Panics if `bank_size` is zero.
+///
+/// # Example
+/// ```
+/// use gb_save_core::{calculate_additive_u16_checksum, calculate_per_bank_checksums, SaveBinary, Size};
+///
+/// let save = SaveBinary::new(vec![1, 1, 2, 2]);
+/// let sums = calculate_per_bank_checksums(&save, Size(2), calculate_additive_u16_checksum).unwrap();
+/// assert_eq!(sums, vec![2, 4]);
+/// ```
+pub fn calculate_per_bank_checksums(
+    save: &SaveBinary,
+    bank_size: Size,
+    checksum_fn: impl Fn(&SaveBinary, AddressRange) -> SaveResult<u16>,
+) -> SaveResult<Vec<u16>> {
+    assert!(bank_size.0 != 0, "bank_size must be non-zero");
+    let banks = save.len() / bank_size.as_usize();
+    let whole_banks =
+        AddressRange::from_start_and_len(Address(0), bank_size.saturating_mul(banks as u32));
+    whole_banks
+        .chunk(bank_size)
+        .map(|bank| checksum_fn(save, bank))
+        .collect()
+}
+
+/// Checks each bank's checksum against the little-endian `u16` stored at `stored_addresses[i]`.
+///
+/// # Errors
+/// Returns [`SaveError::ChecksumMismatch`] (with `which = "bank"`) for the first bank whose
+/// checksum differs, or any error from `checksum_fn` or from reading a stored value. Use
+/// [`calculate_per_bank_checksums`] to find every mismatching bank.
+///
+/// # Panics
+/// Panics if `bank_size` is zero or `stored_addresses` does not have one address per bank.
+pub fn verify_per_bank_checksums(
+    save: &SaveBinary,
+    bank_size: Size,
+    stored_addresses: &[Address],
+    checksum_fn: impl Fn(&SaveBinary, AddressRange) -> SaveResult<u16>,
+) -> SaveResult<()> {
+    let calculated = calculate_per_bank_checksums(save, bank_size, checksum_fn)?;
+    assert_eq!(
+        stored_addresses.len(),
+        calculated.len(),
+        "expected one stored checksum address per bank"
+    );
+
+    for (&address, calculated) in stored_addresses.iter().zip(calculated) {
+        let stored = save.read_u16_le(address)?;
+        if stored != calculated {
+            return Err(SaveError::ChecksumMismatch {
+                which: "bank",
+                stored,
+                calculated,
+            });
+        }
+    }
+    Ok(())
+}
+
+const ADLER32_MODULUS: u32 = 65_521;
+
+/// Largest number of bytes that can be summed before the Adler-32 sums must be reduced to avoid
+/// overflowing a `u32`.
+const ADLER32_NMAX: usize = 5_552;
+
+/// Calculates the Adler-32 checksum of a save byte range.
+///
+/// Two running sums are kept modulo 65521: `a` (starting at 1) adds each byte, and `b` adds each
+/// new `a`. The result is `(b << 16) | a`.
+///
+/// # Errors
+/// Returns an error if `range` is invalid or falls outside the save buffer.
+pub fn calculate_adler32(save: &SaveBinary, range: AddressRange) -> SaveResult<u32> {
+    if range.start.0 >= range.end.0 {
+        return Err(SaveError::InvalidAddressRange { range });
+    }
+
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in save.slice(range)?.chunks(ADLER32_NMAX) {
+        for &byte in chunk {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= ADLER32_MODULUS;
+        b %= ADLER32_MODULUS;
+    }
+    Ok((b << 16) | a)
+}
+
+/// Checks that the Adler-32 checksum of `range` equals `stored`.
+///
+/// # Errors
+/// Returns [`SaveError::ChecksumMismatch32`] (with `which = "adler32"`) if the checksums differ, or
+/// an error if `range` is invalid or falls outside the save buffer.
+pub fn verify_adler32(save: &SaveBinary, range: AddressRange, stored: u32) -> SaveResult<()> {
+    let calculated = calculate_adler32(save, range)?;
+    if calculated != stored {
+        return Err(SaveError::ChecksumMismatch32 {
+            which: "adler32",
+            stored,
+            calculated,
+        });
+    }
+    Ok(())
+}
+
+/// Parameters of a CRC algorithm, in the style of the Rocksoft/"CRC catalogue" model.
+///
+/// `polynomial` and `initial_value` are given unreflected, as listed in CRC catalogues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CrcConfig {
+    /// CRC width in bits (8 to 32).
+    pub width: u8,
+    /// Generator polynomial without the implicit top bit (e.g. `0x1021`).
+    pub polynomial: u32,
+    /// Register value before the first byte.
+    pub initial_value: u32,
+    /// Value XORed into the final register.
+    pub xor_out: u32,
+    /// Processes each input byte least-significant bit first.
+    pub reflect_in: bool,
+    /// Reflects the final register before `xor_out` is applied.
+    pub reflect_out: bool,
+}
+
+impl CrcConfig {
+    /// CRC-16/CCITT-FALSE: polynomial `0x1021`, initial value `0xFFFF`, not reflected.
+    #[must_use]
+    pub const fn crc16_ccitt() -> Self {
+        Self {
+            width: 16,
+            polynomial: 0x1021,
+            initial_value: 0xFFFF,
+            xor_out: 0,
+            reflect_in: false,
+            reflect_out: false,
+        }
+    }
+
+    /// CRC-32 (IEEE 802.3, as used by zlib and PNG).
+    #[must_use]
+    pub const fn crc32_ieee() -> Self {
+        Self {
+            width: 32,
+            polynomial: 0x04C1_1DB7,
+            initial_value: 0xFFFF_FFFF,
+            xor_out: 0xFFFF_FFFF,
+            reflect_in: true,
+            reflect_out: true,
+        }
+    }
+
+    fn mask(&self) -> u32 {
+        u32::MAX >> (32 - u32::from(self.width))
+    }
+
+    fn table(&self) -> [u32; 256] {
+        let shift = u32::from(self.width) - 8;
+        let top_bit = 1u32 << (self.width - 1);
+        let mut table = [0u32; 256];
+        for (byte, entry) in (0u32..).zip(table.iter_mut()) {
+            let mut reg = byte << shift;
+            for _ in 0..8 {
+                reg = if reg & top_bit != 0 {
+                    (reg << 1) ^ self.polynomial
+                } else {
+                    reg << 1
+                };
+            }
+            *entry = reg & self.mask();
+        }
+        table
+    }
+}
+
+/// Calculates a CRC of a save byte range using `config`.
+///
+/// A lookup table is built from `config` on each call.
+///
+/// # Errors
+/// Returns an error if `range` is invalid or falls outside the save buffer.
+///
+/// # Panics
+/// Panics if `config.width` is not between 8 and 32.
+///
+/// # Example
+/// ```
+/// use gb_save_core::{calculate_crc, Address, AddressRange, CrcConfig, SaveBinary};
+///
+/// let save = SaveBinary::new(b"123456789".to_vec());
+/// let range = AddressRange::new(Address(0), Address(9));
+/// assert_eq!(calculate_crc(&save, range, &CrcConfig::crc16_ccitt()).unwrap(), 0x29B1);
+/// ```
+pub fn calculate_crc(
+    save: &SaveBinary,
+    range: AddressRange,
+    config: &CrcConfig,
+) -> SaveResult<u32> {
+    assert!(
+        (8..=32).contains(&config.width),
+        "CRC width must be between 8 and 32 bits"
+    );
+    if range.start.0 >= range.end.0 {
+        return Err(SaveError::InvalidAddressRange { range });
+    }
+
+    let bytes = save.slice(range)?;
+    let table = config.table();
+    let mask = config.mask();
+    let shift = u32::from(config.width) - 8;
+
+    let mut reg = config.initial_value & mask;
+    for &byte in bytes {
+        let byte = if config.reflect_in {
+            byte.reverse_bits()
+        } else {
+            byte
+        };
+        let index = ((reg >> shift) ^ u32::from(byte)) & 0xFF;
+        reg = ((reg << 8) ^ table[index as usize]) & mask;
+    }
+
+    if config.reflect_out {
+        reg = reg.reverse_bits() >> (32 - u32::from(config.width));
+    }
+    Ok((reg ^ config.xor_out) & mask)
 }
 
 #[cfg(test)]
@@ -31,4 +281,187 @@ mod tests {
                 .unwrap();
         assert_eq!(checksum, 0x03FC);
     }
+
+    #[test]
+    fn seed_offsets_the_additive_checksum() {
+        let save = SaveBinary::new(vec![0xFF, 0x10, 0x20, 0x30]);
+        let range = AddressRange::new(Address(0), Address(4));
+        let unseeded = calculate_additive_u16_checksum(&save, range).unwrap();
+
+        assert_eq!(
+            calculate_additive_u16_checksum_seeded(&save, range, 0).unwrap(),
+            unseeded
+        );
+        assert_eq!(
+            calculate_additive_u16_checksum_seeded(&save, range, 0x1234).unwrap(),
+            unseeded + 0x1234
+        );
+        assert_eq!(
+            calculate_additive_u16_checksum_seeded(&save, range, 0xFFFF).unwrap(),
+            unseeded.wrapping_add(0xFFFF)
+        );
+    }
+
+    fn whole(save: &SaveBinary) -> AddressRange {
+        AddressRange::from_start_and_count(Address(0), save.len() as u32)
+    }
+
+    /// Four 8-byte banks; bank `i` holds six bytes of `i + 1` then its checksum at offset 6.
+    fn four_bank_save() -> SaveBinary {
+        let mut save = SaveBinary::new(vec![0; 32]);
+        for bank in 0..4u8 {
+            let start = u32::from(bank) * 8;
+            save.fill(
+                AddressRange::from_start_and_count(Address(start), 6),
+                bank + 1,
+            )
+            .unwrap();
+            save.write_u16_le(Address(start + 6), u16::from(bank + 1) * 6)
+                .unwrap();
+        }
+        save
+    }
+
+    fn data_checksum(save: &SaveBinary, bank: AddressRange) -> SaveResult<u16> {
+        calculate_additive_u16_checksum(save, AddressRange::from_start_and_count(bank.start, 6))
+    }
+
+    const STORED: [Address; 4] = [Address(6), Address(14), Address(22), Address(30)];
+
+    #[test]
+    fn per_bank_checksums_cover_each_bank() {
+        let save = four_bank_save();
+        assert_eq!(
+            calculate_per_bank_checksums(&save, Size(8), data_checksum).unwrap(),
+            vec![6, 12, 18, 24]
+        );
+
+        // Trailing bytes that do not fill a bank are skipped.
+        let save = SaveBinary::new(vec![1; 10]);
+        assert_eq!(
+            calculate_per_bank_checksums(&save, Size(4), calculate_additive_u16_checksum).unwrap(),
+            vec![4, 4]
+        );
+    }
+
+    #[test]
+    fn verify_per_bank_checksums_reports_mismatch() {
+        let mut save = four_bank_save();
+        assert!(verify_per_bank_checksums(&save, Size(8), &STORED, data_checksum).is_ok());
+
+        save.write_u8(Address(17), 0).unwrap();
+        assert!(matches!(
+            verify_per_bank_checksums(&save, Size(8), &STORED, data_checksum),
+            Err(SaveError::ChecksumMismatch {
+                which: "bank",
+                stored: 18,
+                calculated: 15,
+            })
+        ));
+    }
+
+    #[test]
+    fn crc_matches_catalogue_check_values() {
+        let save = SaveBinary::new(b"123456789".to_vec());
+        let range = whole(&save);
+
+        assert_eq!(
+            calculate_crc(&save, range, &CrcConfig::crc16_ccitt()).unwrap(),
+            0x29B1
+        );
+        assert_eq!(
+            calculate_crc(&save, range, &CrcConfig::crc32_ieee()).unwrap(),
+            0xCBF4_3926
+        );
+
+        let crc16_arc = CrcConfig {
+            width: 16,
+            polynomial: 0x8005,
+            initial_value: 0,
+            xor_out: 0,
+            reflect_in: true,
+            reflect_out: true,
+        };
+        assert_eq!(calculate_crc(&save, range, &crc16_arc).unwrap(), 0xBB3D);
+
+        let crc8 = CrcConfig {
+            width: 8,
+            polynomial: 0x07,
+            initial_value: 0,
+            xor_out: 0,
+            reflect_in: false,
+            reflect_out: false,
+        };
+        assert_eq!(calculate_crc(&save, range, &crc8).unwrap(), 0xF4);
+    }
+
+    #[test]
+    fn crc_rejects_out_of_bounds_ranges() {
+        let save = SaveBinary::new(vec![0; 4]);
+        assert!(matches!(
+            calculate_crc(
+                &save,
+                AddressRange::new(Address(0), Address(5)),
+                &CrcConfig::crc32_ieee()
+            ),
+            Err(SaveError::RangeOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "CRC width must be between 8 and 32 bits")]
+    fn crc_rejects_narrow_widths() {
+        let save = SaveBinary::new(vec![0; 4]);
+        let config = CrcConfig {
+            width: 4,
+            ..CrcConfig::crc16_ccitt()
+        };
+        let _ = calculate_crc(&save, whole(&save), &config);
+    }
+
+    #[test]
+    fn adler32_matches_reference_vectors() {
+        let save = SaveBinary::new(b"Wikipedia".to_vec());
+        assert_eq!(calculate_adler32(&save, whole(&save)).unwrap(), 0x11E6_0398);
+
+        let save = SaveBinary::new(b"Mark Adler".to_vec());
+        assert_eq!(calculate_adler32(&save, whole(&save)).unwrap(), 0x1307_0394);
+
+        // Long enough to need several modulo reductions.
+        let save = SaveBinary::new(vec![0xFF; 0x8000]);
+        assert_eq!(calculate_adler32(&save, whole(&save)).unwrap(), 0x7FC2_8772);
+    }
+
+    #[test]
+    fn adler32_rejects_out_of_bounds_ranges() {
+        let save = SaveBinary::new(vec![0; 4]);
+        assert!(matches!(
+            calculate_adler32(&save, AddressRange::new(Address(2), Address(8))),
+            Err(SaveError::RangeOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            calculate_adler32(&save, AddressRange::new(Address(2), Address(2))),
+            Err(SaveError::InvalidAddressRange { .. })
+        ));
+    }
+
+    #[test]
+    fn verify_adler32_reports_mismatch() {
+        let save = SaveBinary::new(b"Wikipedia".to_vec());
+        assert!(verify_adler32(&save, whole(&save), 0x11E6_0398).is_ok());
+        assert!(matches!(
+            verify_adler32(&save, whole(&save), 1),
+            Err(SaveError::ChecksumMismatch32 {
+                which: "adler32",
+                stored: 1,
+                calculated: 0x11E6_0398,
+            })
+        ));
+        assert_eq!(
+            verify_adler32(&save, whole(&save), 1)
+                .unwrap_err()
+                .to_string(),
+            "adler32 checksum mismatch: stored=0x00000001 calculated=0x11E60398"
+        );
+    }
 }
@@ -4,7 +4,7 @@ use crate::{Address, AddressRange, Size};
 pub type SaveResult<T> = Result<T, SaveError>;
 
 /// Errors returned when reading, validating, or patching a save buffer.
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, thiserror::Error)]
 pub enum SaveError {
     /// The provided save buffer is smaller than the minimum size required by the operation.
     #[error("save buffer too small: expected at least {min} bytes, got {actual}")]
@@ -96,7 +96,8 @@ pub enum SaveError {
         base: String,
     },
 
-    /// A migration was requested from a newer version to an older version.
+    /// A migration plan was requested in a direction the resolver does not handle (for example,
+    /// an upgrade resolver asked to go from a newer version to an older one).
     #[error("unsupported migration direction: {current_version} -> {target_version}")]
     UnsupportedMigrationDirection {
         /// The current save version.
@@ -105,6 +106,18 @@ pub enum SaveError {
         target_version: u16,
     },
 
+    /// A save version is not one the game (or its migration set) knows about.
+    #[error(
+        "unsupported save version {version} (supported: {})",
+        format_versions(supported)
+    )]
+    VersionNotSupported {
+        /// The requested or detected save version.
+        version: u16,
+        /// The supported versions, in ascending order.
+        supported: Vec<u16>,
+    },
+
     /// A migration plan could not be built because an intermediate step is missing.
     #[error("missing migration step from {from_version} to reach {target_version}")]
     MissingMigrationStep {
@@ -114,6 +127,16 @@ pub enum SaveError {
         target_version: u16,
     },
 
+    /// A specific patch failed; wraps the underlying error with the patch id.
+    #[error("patch {id} failed: {source}")]
+    PatchFailed {
+        /// The id of the patch that failed.
+        id: &'static str,
+        /// The error returned by the patch.
+        #[source]
+        source: Box<SaveError>,
+    },
+
     /// A fix patch was requested with a `dev_type` that is not known.
     #[error("unknown fix patch: dev_type={dev_type}")]
     UnknownFixPatch {
@@ -121,6 +144,22 @@ pub enum SaveError {
         dev_type: u8,
     },
 
+    /// A patch was requested by an id that is not known.
+    #[error("unknown patch id: {id} (known ids: {})", format_ids(known))]
+    UnknownPatchId {
+        /// The requested patch id.
+        id: String,
+        /// The ids that are known, in display order.
+        known: Vec<String>,
+    },
+
+    /// A patch was registered with an id that is already in use.
+    #[error("duplicate patch id: {id}")]
+    DuplicatePatchId {
+        /// The conflicting patch id.
+        id: &'static str,
+    },
+
     /// The requested feature exists conceptually but has not been implemented.
     #[error("not implemented: {feature}")]
     NotImplemented {
@@ -139,10 +178,151 @@ pub enum SaveError {
         calculated: u16,
     },
 
+    /// A computed 32-bit checksum (such as Adler-32 or CRC-32) does not match the stored value.
+    #[error("{which} checksum mismatch: stored=0x{stored:08X} calculated=0x{calculated:08X}")]
+    ChecksumMismatch32 {
+        /// Identifies which checksum was validated (e.g. "adler32").
+        which: &'static str,
+        /// The checksum stored in the save.
+        stored: u32,
+        /// The checksum computed from the save data.
+        calculated: u32,
+    },
+
+    /// The save contains bytes that cannot be valid (for example a magic byte mismatch).
+    #[error("corrupted save at {offset}: {reason}")]
+    CorruptedSave {
+        /// The absolute offset of the first corrupted byte.
+        offset: Address,
+        /// A human-readable description of the corruption.
+        reason: String,
+    },
+
     /// The save is structurally valid but in a state that prevents safe patching.
     #[error("invalid save state: {reason}")]
     InvalidSaveState {
         /// A human-readable explanation of why patching is unsafe.
         reason: String,
     },
+
+    /// Several independent checks failed; see [`collect_errors`].
+    #[error("{} errors:\n{}", .0.len(), format_errors(.0))]
+    MultipleErrors(Vec<SaveError>),
+}
+
+/// Runs every check and reports all failures together.
+///
+/// Returns `Ok(())` if every check passed, the error itself if exactly one check failed, and
+/// otherwise [`SaveError::MultipleErrors`] holding each failure in order.
+///
+/// # Errors
+/// Returns the single failure, or [`SaveError::MultipleErrors`] if more than one check failed.
+///
+/// # Example
+/// ```
+/// use gb_save_core::{collect_errors, SaveError};
+///
+/// let err = collect_errors([
+///     Ok(()),
+///     Err(SaveError::InvalidBitIndex { bit: 8 }),
+///     Err(SaveError::UnknownFixPatch { dev_type: 3 }),
+/// ])
+/// .unwrap_err();
+/// assert!(matches!(err, SaveError::MultipleErrors(ref errors) if errors.len() == 2));
+///
+/// let err = collect_errors([Ok(()), Err(SaveError::InvalidBitIndex { bit: 8 })]).unwrap_err();
+/// assert!(matches!(err, SaveError::InvalidBitIndex { bit: 8 }));
+/// ```
+pub fn collect_errors(checks: impl IntoIterator<Item = SaveResult<()>>) -> SaveResult<()> {
+    let mut errors: Vec<SaveError> = checks.into_iter().filter_map(Result::err).collect();
+    match errors.len() {
+        0 => Ok(()),
+        1 => Err(errors.remove(0)),
+        _ => Err(SaveError::MultipleErrors(errors)),
+    }
+}
+
+fn format_errors(errors: &[SaveError]) -> String {
+    errors
+        .iter()
+        .map(|e| format!("  - {e}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_ids(ids: &[String]) -> String {
+    if ids.is_empty() {
+        return "none".to_string();
+    }
+
+    ids.join(", ")
+}
+
+fn format_versions(versions: &[u16]) -> String {
+    if versions.is_empty() {
+        return "none".to_string();
+    }
+
+    versions
+        .iter()
+        .map(u16::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Errors returned when parsing a hex string with [`crate::SaveBinary::from_hex_str`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum HexParseError {
+    /// The input had an odd number of hex digits.
+    #[error("hex string has odd length: {len}")]
+    OddLength {
+        /// The input length in characters.
+        len: usize,
+    },
+
+    /// The input contained a character that is not a hex digit.
+    #[error("invalid hex character {ch:?} at index {index}")]
+    InvalidCharacter {
+        /// The offending character.
+        ch: char,
+        /// The character's byte index in the input.
+        index: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_errors_passes_when_all_checks_pass() {
+        assert!(collect_errors([Ok(()), Ok(())]).is_ok());
+        assert!(collect_errors(std::iter::empty()).is_ok());
+    }
+
+    #[test]
+    fn collect_errors_returns_a_single_failure_unwrapped() {
+        let err =
+            collect_errors([Ok(()), Err(SaveError::UnknownFixPatch { dev_type: 3 })]).unwrap_err();
+        assert!(matches!(err, SaveError::UnknownFixPatch { dev_type: 3 }));
+    }
+
+    #[test]
+    fn collect_errors_keeps_every_failure() {
+        let err = collect_errors([
+            Err(SaveError::InvalidBitIndex { bit: 8 }),
+            Ok(()),
+            Err(SaveError::UnknownFixPatch { dev_type: 3 }),
+        ])
+        .unwrap_err();
+
+        let SaveError::MultipleErrors(errors) = &err else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            err.to_string(),
+            "2 errors:\n  - invalid bit index: 8 (expected 0..=7)\n  - unknown fix patch: dev_type=3"
+        );
+    }
 }
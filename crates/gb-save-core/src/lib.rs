@@ -35,13 +35,30 @@ mod save_binary;
 mod symbol_database;
 mod types;
 
-pub use checksum::calculate_additive_u16_checksum;
-pub use error::{SaveError, SaveResult};
+pub use checksum::{
+    calculate_additive_u16_checksum, calculate_additive_u16_checksum_seeded, calculate_adler32,
+    calculate_crc, calculate_per_bank_checksums, verify_adler32, verify_per_bank_checksums,
+    CrcConfig,
+};
+pub use error::{collect_errors, HexParseError, SaveError, SaveResult};
+#[cfg(feature = "log")]
+pub use patch_framework::LogCrateSink;
+#[cfg(feature = "tracing")]
+pub use patch_framework::TracingSink;
 pub use patch_framework::{
-    resolve_migration_plan, NoopPatchLogSink, Patch, PatchKind, PatchLogEntry, PatchLogLevel,
-    PatchLogSink, PatchMetadata, VecPatchLogSink,
+    apply_and_diff, apply_atomic, apply_migration_plan_atomic, apply_migration_plan_with_progress,
+    apply_or_rollback, migration_graph_to_dot, resolve_downgrade_plan, resolve_migration_plan,
+    resolve_migration_plan_with_max_steps, validate_migration_plan, ConditionalPatch,
+    CountingPatchLogSink, FilteredPatchLogSink, NoopPatchLogSink, Patch, PatchComposite, PatchDiff,
+    PatchKind, PatchLogEntry, PatchLogLevel, PatchLogSink, PatchMetadata, PatchMetadataBuilder,
+    PatchPlan, PatchRegistry, TeePatchLogSink, VecPatchLogSink, VecPatchLogSinkDedup,
+    WritePatchLogSink,
+};
+pub use remap::{
+    map_bitset, remap_bitset_counted, remap_bitset_with_log, remap_fixed_len_u16_skip_zero,
+    remap_fixed_len_u8_skip_zero, remap_fixed_len_u8_with_lookup, remap_sparse_bitset,
+    remap_u16_values, remap_zero_terminated_u16, remap_zero_terminated_u8,
 };
-pub use remap::{map_bitset, remap_fixed_len_u8_skip_zero, remap_zero_terminated_u8};
-pub use save_binary::SaveBinary;
+pub use save_binary::{RecordingSaveBinary, SaveBinary};
 pub use symbol_database::{Symbol, SymbolDatabase};
-pub use types::{bits_to_bytes, Address, AddressRange, Size};
+pub use types::{bits_to_bytes, bytes_to_bits, Address, AddressRange, Size};
@@ -1,23 +1,54 @@
-use crate::{SaveBinary, SaveError, SaveResult, SymbolDatabase};
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::io;
+use std::ops::Deref;
+
+use crate::{Address, SaveBinary, SaveError, SaveResult, SymbolDatabase};
 
 /// Severity level for patch log output.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Levels are ordered by severity: `Info < Warning < Error`. With the `serde` feature, levels
+/// serialize as their [`as_str`](PatchLogLevel::as_str) labels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum PatchLogLevel {
     /// Informational message.
     Info,
     /// Warning indicating a recoverable issue or unexpected state.
+    #[cfg_attr(feature = "serde", serde(rename = "warn"))]
     Warning,
     /// Error indicating patching cannot safely proceed.
     Error,
 }
 
+impl PatchLogLevel {
+    /// Returns the short lowercase label used in rendered logs (`"info"`, `"warn"`, `"error"`).
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warn",
+            Self::Error => "error",
+        }
+    }
+}
+
 /// A structured log entry emitted during patching.
+///
+/// With the `serde` feature this can be serialized and deserialized. Entries built in code borrow
+/// their `source`; deserialized entries own it.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PatchLogEntry {
     /// The severity of the log entry.
     pub level: PatchLogLevel,
     /// A stable identifier for where the log came from (e.g. a patch id).
-    pub source: &'static str,
+    pub source: Cow<'static, str>,
     /// Human-readable log message.
     pub message: String,
 }
@@ -27,7 +58,7 @@ impl PatchLogEntry {
     pub fn info(source: &'static str, message: impl Into<String>) -> Self {
         Self {
             level: PatchLogLevel::Info,
-            source,
+            source: Cow::Borrowed(source),
             message: message.into(),
         }
     }
@@ -36,7 +67,7 @@ impl PatchLogEntry {
     pub fn warning(source: &'static str, message: impl Into<String>) -> Self {
         Self {
             level: PatchLogLevel::Warning,
-            source,
+            source: Cow::Borrowed(source),
             message: message.into(),
         }
     }
@@ -45,7 +76,7 @@ impl PatchLogEntry {
     pub fn error(source: &'static str, message: impl Into<String>) -> Self {
         Self {
             level: PatchLogLevel::Error,
-            source,
+            source: Cow::Borrowed(source),
             message: message.into(),
         }
     }
@@ -72,6 +103,12 @@ pub trait PatchLogSink {
     }
 }
 
+impl<S: PatchLogSink + ?Sized> PatchLogSink for &mut S {
+    fn push(&mut self, entry: PatchLogEntry) {
+        (**self).push(entry);
+    }
+}
+
 /// A log sink that discards all entries.
 #[derive(Debug, Default)]
 pub struct NoopPatchLogSink;
@@ -104,6 +141,293 @@ impl PatchLogSink for VecPatchLogSink {
     }
 }
 
+/// An in-memory log sink that caps how many times an identical entry is kept.
+///
+/// The first `max_duplicates` copies of each `(level, source, message)` entry are kept. Later
+/// copies are dropped and replaced by a single `"{message} ... (suppressed N duplicates)"` entry,
+/// placed where the first dropped copy would have been. With `max_duplicates == 0` only that
+/// entry is kept, and `N` counts the copies after the first.
+#[derive(Debug)]
+pub struct VecPatchLogSinkDedup {
+    inner: VecPatchLogSink,
+    max_duplicates: usize,
+    seen: HashMap<PatchLogEntry, usize>,
+    /// Maps each suppressed entry to the index of its summary entry and the suppressed count.
+    suppressed: HashMap<PatchLogEntry, (usize, usize)>,
+}
+
+impl VecPatchLogSinkDedup {
+    /// Creates an empty sink that keeps at most `max_duplicates` copies of each entry.
+    #[must_use]
+    pub fn new(max_duplicates: usize) -> Self {
+        Self {
+            inner: VecPatchLogSink::new(),
+            max_duplicates,
+            seen: HashMap::new(),
+            suppressed: HashMap::new(),
+        }
+    }
+
+    /// Consumes the sink and returns the kept entries, including suppression summaries.
+    pub fn into_entries(self) -> Vec<PatchLogEntry> {
+        let mut entries = self.inner.into_entries();
+        for (entry, (index, count)) in self.suppressed {
+            if count == 0 {
+                continue;
+            }
+            entries[index].message = format!(
+                "{} ... (suppressed {count} duplicate{})",
+                entry.message,
+                if count == 1 { "" } else { "s" }
+            );
+        }
+        entries
+    }
+}
+
+impl PatchLogSink for VecPatchLogSinkDedup {
+    fn push(&mut self, entry: PatchLogEntry) {
+        let seen = self.seen.entry(entry.clone()).or_insert(0);
+        if *seen < self.max_duplicates {
+            *seen += 1;
+            self.inner.push(entry);
+            return;
+        }
+
+        if let Some((_, count)) = self.suppressed.get_mut(&entry) {
+            *count += 1;
+            return;
+        }
+
+        // With no copies kept, the first copy is the original rather than a duplicate.
+        let count = usize::from(self.max_duplicates > 0);
+        let index = self.inner.entries.len();
+        self.inner.push(entry.clone());
+        self.suppressed.insert(entry, (index, count));
+    }
+}
+
+/// A log sink that only tallies entries by level.
+///
+/// Useful for asserting that a patch emits no warnings or errors without keeping the messages.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CountingPatchLogSink {
+    /// Number of info entries seen.
+    pub info_count: u32,
+    /// Number of warning entries seen.
+    pub warn_count: u32,
+    /// Number of error entries seen.
+    pub error_count: u32,
+}
+
+impl CountingPatchLogSink {
+    /// Creates a sink with all counts at zero.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of entries seen at any level.
+    #[must_use]
+    pub fn total(&self) -> u32 {
+        self.info_count + self.warn_count + self.error_count
+    }
+
+    /// Returns true if at least one error entry was seen.
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.error_count > 0
+    }
+}
+
+impl PatchLogSink for CountingPatchLogSink {
+    fn push(&mut self, entry: PatchLogEntry) {
+        let count = match entry.level {
+            PatchLogLevel::Info => &mut self.info_count,
+            PatchLogLevel::Warning => &mut self.warn_count,
+            PatchLogLevel::Error => &mut self.error_count,
+        };
+        *count = count.saturating_add(1);
+    }
+}
+
+/// A log sink that writes each entry as a formatted line to an [`io::Write`].
+///
+/// Entries are written immediately as `[{level}] {source}: {message}` (or via a custom formatter),
+/// so nothing is buffered in memory beyond what the writer itself buffers. Entries below
+/// `min_level` are dropped. Write errors are ignored because [`PatchLogSink::push`] cannot report
+/// them.
+#[derive(Debug)]
+pub struct WritePatchLogSink<W: io::Write> {
+    writer: W,
+    min_level: PatchLogLevel,
+    format: fn(&PatchLogEntry) -> String,
+}
+
+impl<W: io::Write> WritePatchLogSink<W> {
+    /// Creates a sink that writes entries at or above `min_level` to `writer`.
+    pub fn new(writer: W, min_level: PatchLogLevel) -> Self {
+        Self::with_format(writer, min_level, format_log_line)
+    }
+
+    /// Like [`WritePatchLogSink::new`], but renders each line with `format` (without the trailing
+    /// newline).
+    pub fn with_format(
+        writer: W,
+        min_level: PatchLogLevel,
+        format: fn(&PatchLogEntry) -> String,
+    ) -> Self {
+        Self {
+            writer,
+            min_level,
+            format,
+        }
+    }
+
+    /// Consumes the sink and returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: io::Write> PatchLogSink for WritePatchLogSink<W> {
+    fn push(&mut self, entry: PatchLogEntry) {
+        if entry.level < self.min_level {
+            return;
+        }
+
+        let _ = writeln!(self.writer, "{}", (self.format)(&entry));
+    }
+}
+
+fn format_log_line(entry: &PatchLogEntry) -> String {
+    format!(
+        "[{level}] {source}: {message}",
+        level = entry.level.as_str(),
+        source = entry.source,
+        message = entry.message
+    )
+}
+
+/// A log sink that forwards entries to the [`log`](https://docs.rs/log) crate facade.
+///
+/// Each entry is emitted at the matching `log` level as `{source}: {message}`, with `target` as
+/// the record target. If no logger is installed the entries are silently dropped.
+#[cfg(feature = "log")]
+#[derive(Debug, Clone, Copy)]
+pub struct LogCrateSink {
+    /// The target used for every emitted [`log::Record`].
+    pub target: &'static str,
+}
+
+#[cfg(feature = "log")]
+impl LogCrateSink {
+    /// Creates a sink that logs under `target`.
+    #[must_use]
+    pub fn new(target: &'static str) -> Self {
+        Self { target }
+    }
+}
+
+#[cfg(feature = "log")]
+impl PatchLogSink for LogCrateSink {
+    fn push(&mut self, entry: PatchLogEntry) {
+        let target = self.target;
+        let (source, message) = (&entry.source, &entry.message);
+        match entry.level {
+            PatchLogLevel::Info => log::info!(target: target, "{source}: {message}"),
+            PatchLogLevel::Warning => log::warn!(target: target, "{source}: {message}"),
+            PatchLogLevel::Error => log::error!(target: target, "{source}: {message}"),
+        }
+    }
+}
+
+/// A log sink that emits each entry as a [`tracing`](https://docs.rs/tracing) event.
+///
+/// Events carry the fields `patch.source` and `patch.message`. Info events are recorded inside a
+/// `patch` span whose `id` field is the entry's source (usually the patch id), so they nest under
+/// the patch in trace output.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TracingSink;
+
+#[cfg(feature = "tracing")]
+impl PatchLogSink for TracingSink {
+    fn push(&mut self, entry: PatchLogEntry) {
+        let source = entry.source.as_ref();
+        let message = entry.message.as_str();
+        match entry.level {
+            PatchLogLevel::Info => {
+                let span = tracing::info_span!("patch", id = source);
+                let _guard = span.enter();
+                tracing::info!(patch.source = source, patch.message = message);
+            }
+            PatchLogLevel::Warning => {
+                tracing::warn!(patch.source = source, patch.message = message);
+            }
+            PatchLogLevel::Error => {
+                tracing::error!(patch.source = source, patch.message = message);
+            }
+        }
+    }
+}
+
+/// A log sink that forwards only entries at or above `min_level` to an inner sink.
+#[derive(Debug)]
+pub struct FilteredPatchLogSink<S: PatchLogSink> {
+    inner: S,
+    min_level: PatchLogLevel,
+}
+
+impl<S: PatchLogSink> FilteredPatchLogSink<S> {
+    /// Wraps `inner`, dropping entries below `min_level`.
+    pub fn new(inner: S, min_level: PatchLogLevel) -> Self {
+        Self { inner, min_level }
+    }
+
+    /// Consumes the filter and returns the inner sink.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: PatchLogSink> PatchLogSink for FilteredPatchLogSink<S> {
+    fn push(&mut self, entry: PatchLogEntry) {
+        if entry.level >= self.min_level {
+            self.inner.push(entry);
+        }
+    }
+}
+
+/// A log sink that forwards every entry to two sinks.
+///
+/// Combine with [`FilteredPatchLogSink`] to route different levels to different destinations.
+/// Because `&mut S` is itself a sink, either side may borrow an existing sink.
+#[derive(Debug)]
+pub struct TeePatchLogSink<A: PatchLogSink, B: PatchLogSink> {
+    first: A,
+    second: B,
+}
+
+impl<A: PatchLogSink, B: PatchLogSink> TeePatchLogSink<A, B> {
+    /// Creates a sink that forwards to `first` and then `second`.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Consumes the tee and returns both inner sinks.
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A: PatchLogSink, B: PatchLogSink> PatchLogSink for TeePatchLogSink<A, B> {
+    fn push(&mut self, entry: PatchLogEntry) {
+        self.first.push(entry.clone());
+        self.second.push(entry);
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 /// Describes whether a patch is a migration or a non-migrating fix.
 pub enum PatchKind {
@@ -124,6 +448,126 @@ pub struct PatchMetadata {
     pub from_version: Option<u16>,
     /// Destination version (for migrations).
     pub to_version: Option<u16>,
+    /// Human-readable summary of what the patch does (may be empty).
+    pub description: &'static str,
+    /// Human-readable name; [`PatchMetadata::label`] falls back to `id` when absent.
+    pub display_name: Option<&'static str>,
+}
+
+impl PatchMetadata {
+    /// Starts building metadata; see [`PatchMetadataBuilder`].
+    pub const fn builder() -> PatchMetadataBuilder {
+        PatchMetadataBuilder::new()
+    }
+
+    /// Returns the name to show users: `display_name` if set, otherwise `id`.
+    #[must_use]
+    pub fn label(&self) -> &'static str {
+        self.display_name.unwrap_or(self.id)
+    }
+}
+
+/// Builder for [`PatchMetadata`] that leaves unset optional fields empty.
+///
+/// Patches default to [`PatchKind::Fix`] until [`PatchMetadataBuilder::migration`] is called. All
+/// methods are `const`, so metadata can be built in constants.
+///
+/// # Example
+/// ```
+/// use gb_save_core::{PatchKind, PatchMetadata};
+///
+/// let meta = PatchMetadata::builder()
+///     .id("m1_to_2")
+///     .migration(1, 2)
+///     .description("Moves the bag to its v2 location.")
+///     .build();
+/// assert_eq!(meta.kind, PatchKind::Migration);
+/// assert_eq!(meta.to_version, Some(2));
+/// assert_eq!(meta.display_name, None);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct PatchMetadataBuilder {
+    id: Option<&'static str>,
+    kind: PatchKind,
+    from_version: Option<u16>,
+    to_version: Option<u16>,
+    description: &'static str,
+    display_name: Option<&'static str>,
+}
+
+impl Default for PatchMetadataBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PatchMetadataBuilder {
+    /// Creates a builder for a fix patch with no id, description, or display name.
+    pub const fn new() -> Self {
+        Self {
+            id: None,
+            kind: PatchKind::Fix,
+            from_version: None,
+            to_version: None,
+            description: "",
+            display_name: None,
+        }
+    }
+
+    /// Sets the stable patch id (required).
+    pub const fn id(mut self, id: &'static str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Marks the patch as a migration from `from` to `to`.
+    pub const fn migration(mut self, from: u16, to: u16) -> Self {
+        self.kind = PatchKind::Migration;
+        self.from_version = Some(from);
+        self.to_version = Some(to);
+        self
+    }
+
+    /// Marks the patch as a fix, clearing any migration versions.
+    pub const fn fix(mut self) -> Self {
+        self.kind = PatchKind::Fix;
+        self.from_version = None;
+        self.to_version = None;
+        self
+    }
+
+    /// Sets the human-readable description.
+    pub const fn description(mut self, description: &'static str) -> Self {
+        self.description = description;
+        self
+    }
+
+    /// Sets the name shown to users instead of the id.
+    pub const fn display_name(mut self, display_name: &'static str) -> Self {
+        self.display_name = Some(display_name);
+        self
+    }
+
+    /// Finishes the metadata.
+    ///
+    /// # Panics
+    /// Panics if [`PatchMetadataBuilder::id`] was never called.
+    #[must_use]
+    pub const fn build(self) -> PatchMetadata {
+        let Some(id) = self.id else {
+            panic!("PatchMetadataBuilder::build called without an id");
+        };
+
+        PatchMetadata {
+            id,
+            kind: self.kind,
+            from_version: self.from_version,
+            to_version: self.to_version,
+            description: self.description,
+            display_name: self.display_name,
+        }
+    }
 }
 
 /// A patch that can be applied to a save buffer.
@@ -151,22 +595,301 @@ pub trait Patch: std::fmt::Debug + Send + Sync {
         let _ = log;
         self.apply(save, symbols)
     }
+
+    /// Reverts a migration, converting a save at `to_version` back to `from_version`.
+    ///
+    /// Only migrations that support downgrades need to override this. The default returns
+    /// [`SaveError::NotImplemented`].
+    ///
+    /// # Errors
+    /// Returns an error if the patch does not support reversal or the save cannot be downgraded
+    /// safely.
+    fn apply_reverse(&self, save: &mut SaveBinary, symbols: &SymbolDatabase) -> SaveResult<()> {
+        let _ = (save, symbols);
+        Err(SaveError::NotImplemented {
+            feature: format!("reverse migration for {}", self.metadata().id),
+        })
+    }
+}
+
+/// Checks that every step in `plan` would apply cleanly, without modifying `save`.
+///
+/// Each step is applied in sequence to a clone of `save`, so later steps see the output of earlier
+/// ones. Downgrade plans are checked with [`Patch::apply_reverse`]. `symbols_for` is called with
+/// the version each step reads: its `from_version`, or its `to_version` in a downgrade plan. An
+/// empty plan (the save is already at the target version) validates immediately.
+///
+/// # Errors
+/// Returns [`SaveError::PatchFailed`] wrapping the first step error, tagged with that step's id.
+pub fn validate_migration_plan(
+    plan: &PatchPlan,
+    save: &SaveBinary,
+    symbols_for: impl Fn(u16) -> SaveResult<SymbolDatabase>,
+) -> SaveResult<()> {
+    if plan.is_empty() {
+        return Ok(());
+    }
+
+    let mut scratch = save.clone();
+    for patch in plan {
+        if let Err(e) = apply_plan_step(plan, *patch, &mut scratch, &symbols_for) {
+            return Err(SaveError::PatchFailed {
+                id: patch.metadata().id,
+                source: Box::new(e),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Applies one step of `plan` in the plan's direction.
+///
+/// Upgrade steps read the `from_version` layout and call [`Patch::apply`]; downgrade steps read
+/// the `to_version` layout and call [`Patch::apply_reverse`].
+fn apply_plan_step(
+    plan: &PatchPlan,
+    patch: &dyn Patch,
+    save: &mut SaveBinary,
+    symbols_for: impl Fn(u16) -> SaveResult<SymbolDatabase>,
+) -> SaveResult<()> {
+    let meta = patch.metadata();
+    let (source, field) = if plan.is_downgrade() {
+        (meta.to_version, "to_version")
+    } else {
+        (meta.from_version, "from_version")
+    };
+    let source = source.ok_or_else(|| SaveError::InvalidSaveState {
+        reason: format!("migration patch {} has no {field}", meta.id),
+    })?;
+    let symbols = symbols_for(source)?;
+
+    if plan.is_downgrade() {
+        patch.apply_reverse(save, &symbols)
+    } else {
+        patch.apply(save, &symbols)
+    }
+}
+
+/// A lookup table of patches keyed by their metadata id.
+///
+/// Game crates typically build one registry at startup containing every migration and fix, then
+/// select patches by id or by kind. Patches are kept in registration order.
+///
+/// # Example
+/// ```
+/// use gb_save_core::PatchRegistry;
+///
+/// let registry = PatchRegistry::new();
+/// assert!(registry.lookup("example.fix").is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PatchRegistry {
+    patches: Vec<&'static dyn Patch>,
+}
+
+impl PatchRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a patch to the registry.
+    ///
+    /// # Errors
+    /// Returns [`SaveError::DuplicatePatchId`] if a patch with the same id is already registered.
+    pub fn register(&mut self, patch: &'static dyn Patch) -> SaveResult<()> {
+        let id = patch.metadata().id;
+        if self.lookup(id).is_some() {
+            return Err(SaveError::DuplicatePatchId { id });
+        }
+
+        self.patches.push(patch);
+        Ok(())
+    }
+
+    /// Looks up a patch by id.
+    #[must_use]
+    pub fn lookup(&self, id: &str) -> Option<&'static dyn Patch> {
+        self.patches.iter().find(|p| p.metadata().id == id).copied()
+    }
+
+    /// Iterates all registered patches in registration order.
+    pub fn iter(&self) -> impl Iterator<Item = &'static dyn Patch> + '_ {
+        self.patches.iter().copied()
+    }
+
+    /// Returns all registered migration patches.
+    #[must_use]
+    pub fn all_migrations(&self) -> Vec<&'static dyn Patch> {
+        self.of_kind(PatchKind::Migration)
+    }
+
+    /// Returns all registered fix patches.
+    #[must_use]
+    pub fn all_fixes(&self) -> Vec<&'static dyn Patch> {
+        self.of_kind(PatchKind::Fix)
+    }
+
+    fn of_kind(&self, kind: PatchKind) -> Vec<&'static dyn Patch> {
+        self.iter().filter(|p| p.metadata().kind == kind).collect()
+    }
+}
+
+/// An ordered list of migration patches produced by [`resolve_migration_plan`] or
+/// [`resolve_downgrade_plan`].
+///
+/// `PatchPlan` dereferences to a slice of patches, so it can be iterated and indexed like the
+/// `Vec` it wraps. Its [`Display`](fmt::Display) form shows the version chain, e.g.
+/// `v1 → v2 (id1) → v3 (id2)`.
+#[derive(Debug, Clone, Default)]
+pub struct PatchPlan {
+    patches: Vec<&'static dyn Patch>,
+    downgrade: bool,
+}
+
+impl PatchPlan {
+    /// Wraps an ordered list of upgrade steps.
+    #[must_use]
+    pub fn new(patches: Vec<&'static dyn Patch>) -> Self {
+        Self {
+            patches,
+            downgrade: false,
+        }
+    }
+
+    /// Wraps an ordered list of downgrade steps, each applied with [`Patch::apply_reverse`].
+    #[must_use]
+    pub fn downgrade(patches: Vec<&'static dyn Patch>) -> Self {
+        Self {
+            patches,
+            downgrade: true,
+        }
+    }
+
+    /// Returns true if the steps move the save to an older version.
+    #[must_use]
+    pub fn is_downgrade(&self) -> bool {
+        self.downgrade
+    }
+
+    /// Returns the number of steps in the plan.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.patches.len()
+    }
+
+    /// Returns true if the plan has no steps.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.patches.is_empty()
+    }
+
+    /// Returns the id of each step in order.
+    #[must_use]
+    pub fn ids(&self) -> Vec<&'static str> {
+        self.patches.iter().map(|p| p.metadata().id).collect()
+    }
+
+    /// Consumes the plan and returns the underlying patches.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<&'static dyn Patch> {
+        self.patches
+    }
+}
+
+impl Deref for PatchPlan {
+    type Target = [&'static dyn Patch];
+
+    fn deref(&self) -> &Self::Target {
+        &self.patches
+    }
+}
+
+impl IntoIterator for PatchPlan {
+    type Item = &'static dyn Patch;
+    type IntoIter = std::vec::IntoIter<&'static dyn Patch>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.patches.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a PatchPlan {
+    type Item = &'a &'static dyn Patch;
+    type IntoIter = std::slice::Iter<'a, &'static dyn Patch>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.patches.iter()
+    }
+}
+
+impl fmt::Display for PatchPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, patch) in self.patches.iter().enumerate() {
+            let meta = patch.metadata();
+            let (start, end) = if self.downgrade {
+                (meta.to_version, meta.from_version)
+            } else {
+                (meta.from_version, meta.to_version)
+            };
+
+            if index == 0 {
+                write_version(f, start)?;
+            }
+            f.write_str(" → ")?;
+            write_version(f, end)?;
+            write!(f, " ({})", meta.id)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_version(f: &mut fmt::Formatter<'_>, version: Option<u16>) -> fmt::Result {
+    match version {
+        Some(v) => write!(f, "v{v}"),
+        None => f.write_str("v?"),
+    }
 }
 
 /// Resolves a sequence of migration patches required to reach `target_version`.
 ///
 /// The plan is built by repeatedly finding a migration patch whose `from_version` matches the
-/// current step and whose `to_version` is greater than `from_version`.
+/// current step and whose `to_version` is greater than `from_version`. Use
+/// [`resolve_downgrade_plan`] to move a save to an older version.
+///
+/// Migrations that lead back to an earlier version (including self-loops) are never chosen, so
+/// they do not stop a valid path from resolving. Choosing a step that would revisit a version
+/// already on the path is reported as a cycle.
 ///
 /// # Errors
-/// Returns an error if the requested direction is unsupported or if a required step is missing.
+/// Returns an error if the requested direction is unsupported, if either version does not appear
+/// in any migration ([`SaveError::VersionNotSupported`]), if a required step is missing, or if the
+/// migration graph contains a cycle along the path.
 pub fn resolve_migration_plan(
     migrations: &[&'static dyn Patch],
     current_version: u16,
     target_version: u16,
-) -> SaveResult<Vec<&'static dyn Patch>> {
+) -> SaveResult<PatchPlan> {
+    resolve_migration_plan_with_max_steps(migrations, current_version, target_version, usize::MAX)
+}
+
+/// Like [`resolve_migration_plan`], but fails if the plan would need more than `max_steps`
+/// migrations.
+///
+/// # Errors
+/// Returns [`SaveError::InvalidSaveState`] if the plan exceeds `max_steps`, plus every error
+/// [`resolve_migration_plan`] can return.
+pub fn resolve_migration_plan_with_max_steps(
+    migrations: &[&'static dyn Patch],
+    current_version: u16,
+    target_version: u16,
+    max_steps: usize,
+) -> SaveResult<PatchPlan> {
     if current_version == target_version {
-        return Ok(Vec::new());
+        return Ok(PatchPlan::default());
     }
 
     if current_version > target_version {
@@ -176,7 +899,18 @@ pub fn resolve_migration_plan(
         });
     }
 
+    let known_versions = migration_versions(migrations);
+    for version in [current_version, target_version] {
+        if known_versions.binary_search(&version).is_err() {
+            return Err(SaveError::VersionNotSupported {
+                version,
+                supported: known_versions,
+            });
+        }
+    }
+
     let mut plan: Vec<&'static dyn Patch> = Vec::new();
+    let mut visited: HashSet<u16> = HashSet::from([current_version]);
     let mut v = current_version;
 
     while v != target_version {
@@ -194,13 +928,424 @@ pub fn resolve_migration_plan(
             });
         };
 
+        if plan.len() >= max_steps {
+            return Err(SaveError::InvalidSaveState {
+                reason: format!("migration plan exceeds max_steps ({max_steps})"),
+            });
+        }
+
         let meta = patch.metadata();
         let to = meta.to_version.expect("validated above");
+        if !visited.insert(to) {
+            return Err(migration_cycle_error(to));
+        }
         plan.push(*patch);
         v = to;
     }
 
-    Ok(plan)
+    Ok(PatchPlan::new(plan))
+}
+
+/// Returns every version mentioned by a migration patch, sorted and deduplicated.
+fn migration_versions(migrations: &[&'static dyn Patch]) -> Vec<u16> {
+    let mut versions: Vec<u16> = migrations
+        .iter()
+        .map(|p| p.metadata())
+        .filter(|meta| meta.kind == PatchKind::Migration)
+        .flat_map(|meta| [meta.from_version, meta.to_version])
+        .flatten()
+        .collect();
+    versions.sort_unstable();
+    versions.dedup();
+    versions
+}
+
+fn migration_cycle_error(version: u16) -> SaveError {
+    SaveError::InvalidSaveState {
+        reason: format!("migration cycle detected at version {version}"),
+    }
+}
+
+/// Renders the migration graph as a Graphviz DOT document.
+///
+/// Each version becomes a node and each migration a directed edge from `from_version` to
+/// `to_version`, labeled with the patch id. Fix patches and migrations missing either version are
+/// omitted.
+///
+/// # Example
+/// ```
+/// use gb_save_core::migration_graph_to_dot;
+///
+/// let dot = migration_graph_to_dot(&[]);
+/// assert!(dot.starts_with("digraph migrations {"));
+/// ```
+#[must_use]
+pub fn migration_graph_to_dot(migrations: &[&dyn Patch]) -> String {
+    let edges: Vec<(u16, u16, &'static str)> = migrations
+        .iter()
+        .map(|p| p.metadata())
+        .filter(|meta| meta.kind == PatchKind::Migration)
+        .filter_map(|meta| Some((meta.from_version?, meta.to_version?, meta.id)))
+        .collect();
+
+    let versions: BTreeSet<u16> = edges.iter().flat_map(|&(from, to, _)| [from, to]).collect();
+
+    let mut dot = String::from("digraph migrations {\n");
+    for v in versions {
+        dot.push_str(&format!("    v{v} [label=\"v{v}\"];\n"));
+    }
+    for (from, to, id) in edges {
+        dot.push_str(&format!(
+            "    v{from} -> v{to} [label=\"{}\"];\n",
+            id.replace('\\', "\\\\").replace('"', "\\\"")
+        ));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// A single patch made of several sub-patches applied in order.
+///
+/// This lets a multi-part operation (for example, remapping an array and then updating its length
+/// field) appear as one step to [`resolve_migration_plan`]. If any step fails, the save is rolled
+/// back to its state before the first step, which doubles peak memory usage while it runs.
+///
+/// # Example
+/// ```
+/// use gb_save_core::{PatchComposite, PatchKind, PatchMetadata};
+///
+/// static EMPTY: PatchComposite = PatchComposite::new(
+///     PatchMetadata {
+///         id: "example.composite",
+///         kind: PatchKind::Fix,
+///         from_version: None,
+///         to_version: None,
+///         description: "",
+///         display_name: None,
+///     },
+///     &[],
+/// );
+/// # let _ = &EMPTY;
+/// ```
+#[derive(Debug)]
+pub struct PatchComposite {
+    meta: PatchMetadata,
+    steps: &'static [&'static dyn Patch],
+}
+
+impl PatchComposite {
+    /// Creates a composite patch with its own metadata and an ordered list of steps.
+    #[must_use]
+    pub const fn new(meta: PatchMetadata, steps: &'static [&'static dyn Patch]) -> Self {
+        Self { meta, steps }
+    }
+
+    /// Returns the sub-patches in application order.
+    #[must_use]
+    pub fn steps(&self) -> &'static [&'static dyn Patch] {
+        self.steps
+    }
+}
+
+impl Patch for PatchComposite {
+    fn metadata(&self) -> PatchMetadata {
+        self.meta
+    }
+
+    fn apply(&self, save: &mut SaveBinary, symbols: &SymbolDatabase) -> SaveResult<()> {
+        with_rollback(save, |save| {
+            for step in self.steps {
+                step.apply(save, symbols)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn apply_with_log(
+        &self,
+        save: &mut SaveBinary,
+        symbols: &SymbolDatabase,
+        log: &mut dyn PatchLogSink,
+    ) -> SaveResult<()> {
+        with_rollback(save, |save| {
+            for step in self.steps {
+                step.apply_with_log(save, symbols, log)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// A patch that only runs when a predicate on the save returns `true`.
+///
+/// Metadata is delegated to the inner patch. When the condition is not met, the save is left
+/// untouched and [`Patch::apply_with_log`] records an info entry explaining the skip.
+///
+/// Use a `fn(&SaveBinary) -> bool` condition type to declare one as a `static`.
+pub struct ConditionalPatch<P: Patch, F: Fn(&SaveBinary) -> bool> {
+    inner: P,
+    condition: F,
+}
+
+impl<P: Patch, F: Fn(&SaveBinary) -> bool> ConditionalPatch<P, F> {
+    /// Wraps `inner` so it only applies when `condition(save)` is `true`.
+    pub const fn new(inner: P, condition: F) -> Self {
+        Self { inner, condition }
+    }
+}
+
+impl<P: Patch, F: Fn(&SaveBinary) -> bool> std::fmt::Debug for ConditionalPatch<P, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConditionalPatch")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<P, F> Patch for ConditionalPatch<P, F>
+where
+    P: Patch,
+    F: Fn(&SaveBinary) -> bool + Send + Sync,
+{
+    fn metadata(&self) -> PatchMetadata {
+        self.inner.metadata()
+    }
+
+    fn apply(&self, save: &mut SaveBinary, symbols: &SymbolDatabase) -> SaveResult<()> {
+        if !(self.condition)(save) {
+            return Ok(());
+        }
+
+        self.inner.apply(save, symbols)
+    }
+
+    fn apply_with_log(
+        &self,
+        save: &mut SaveBinary,
+        symbols: &SymbolDatabase,
+        log: &mut dyn PatchLogSink,
+    ) -> SaveResult<()> {
+        if !(self.condition)(save) {
+            log.info(self.metadata().id, "condition not met; skipping patch");
+            return Ok(());
+        }
+
+        self.inner.apply_with_log(save, symbols, log)
+    }
+}
+
+/// Wraps a patch so applying it also reports which bytes changed.
+///
+/// See [`apply_and_diff`] for the free-function form.
+#[derive(Debug, Clone, Copy)]
+pub struct PatchDiff<'a> {
+    patch: &'a dyn Patch,
+}
+
+impl<'a> PatchDiff<'a> {
+    /// Wraps `patch`.
+    #[must_use]
+    pub fn new(patch: &'a dyn Patch) -> Self {
+        Self { patch }
+    }
+
+    /// Applies the wrapped patch and returns `(address, before, after)` for every changed byte.
+    ///
+    /// The save is snapshotted before applying, so peak memory usage is roughly double the save
+    /// size. Only bytes within the shorter of the before/after buffers are compared.
+    ///
+    /// # Errors
+    /// Returns the patch's error; the save may be partially modified in that case.
+    pub fn apply(
+        &self,
+        save: &mut SaveBinary,
+        symbols: &SymbolDatabase,
+    ) -> SaveResult<Vec<(Address, u8, u8)>> {
+        let before = save.clone();
+        self.patch.apply(save, symbols)?;
+        Ok(before.diff(save))
+    }
+}
+
+/// Applies `patch` and returns `(address, before, after)` for every byte it changed.
+///
+/// This is a shorthand for `PatchDiff::new(patch).apply(save, symbols)`.
+///
+/// # Errors
+/// Returns the patch's error; the save may be partially modified in that case.
+pub fn apply_and_diff(
+    patch: &dyn Patch,
+    save: &mut SaveBinary,
+    symbols: &SymbolDatabase,
+) -> SaveResult<Vec<(Address, u8, u8)>> {
+    PatchDiff::new(patch).apply(save, symbols)
+}
+
+/// Applies `patch`, restoring the original save bytes if it fails.
+///
+/// The save is snapshotted before calling [`Patch::apply`], so peak memory usage is roughly
+/// double the save size while the patch runs.
+///
+/// # Errors
+/// Returns the patch's error after the save has been restored.
+pub fn apply_atomic(
+    patch: &dyn Patch,
+    save: &mut SaveBinary,
+    symbols: &SymbolDatabase,
+) -> SaveResult<()> {
+    with_rollback(save, |save| patch.apply(save, symbols))
+}
+
+/// Like [`apply_atomic`], but calls [`Patch::apply_with_log`] and logs the rollback.
+///
+/// On failure the save is restored in place and only the error is returned. Returning the
+/// snapshot alongside the error (`Err((SaveError, SaveBinary))`) would let callers keep both
+/// buffers, but `save` already holds the original bytes, so that would only add a second copy.
+/// Log entries emitted by the patch before it failed are kept, followed by a warning from the
+/// patch id noting the rollback.
+///
+/// # Errors
+/// Returns the patch's error after the save has been restored.
+pub fn apply_or_rollback(
+    patch: &dyn Patch,
+    save: &mut SaveBinary,
+    symbols: &SymbolDatabase,
+    log: &mut dyn PatchLogSink,
+) -> SaveResult<()> {
+    let result = with_rollback(save, |save| patch.apply_with_log(save, symbols, log));
+    if result.is_err() {
+        log.warn(patch.metadata().id, "patch failed; save restored");
+    }
+    result
+}
+
+/// Applies each migration in `plan` in order, rolling back to the pre-plan state on any failure.
+///
+/// Downgrade plans (from [`resolve_downgrade_plan`]) apply each step with
+/// [`Patch::apply_reverse`]. `symbols_for` is called with the version each step reads (its
+/// `from_version`, or its `to_version` when downgrading) to obtain the symbols that describe that
+/// save layout.
+///
+/// The save is snapshotted once before the first step, so peak memory usage is roughly double the
+/// save size while the plan runs.
+///
+/// # Errors
+/// Returns an error if a step is missing the version it reads, if `symbols_for` fails, or if any
+/// step fails. In every case the save is restored to its original bytes first.
+pub fn apply_migration_plan_atomic(
+    plan: &PatchPlan,
+    save: &mut SaveBinary,
+    symbols_for: impl Fn(u16) -> SaveResult<SymbolDatabase>,
+) -> SaveResult<()> {
+    with_rollback(save, |save| {
+        apply_migration_plan_steps(plan, save, symbols_for, |_, _| {})
+    })
+}
+
+/// Applies each migration in `plan` in order, reporting progress after every successful step.
+///
+/// `on_progress(completed, total)` is called once per step, where `total` is `plan.len()`.
+/// Steps run in the plan's direction and `symbols_for` is called as in
+/// [`apply_migration_plan_atomic`].
+///
+/// Unlike [`apply_migration_plan_atomic`], this does not snapshot the save; a failed step leaves it
+/// partially migrated.
+///
+/// # Errors
+/// Returns an error if a step is missing the version it reads, if `symbols_for` fails, or if any
+/// step fails.
+pub fn apply_migration_plan_with_progress(
+    plan: &PatchPlan,
+    save: &mut SaveBinary,
+    symbols_for: impl Fn(u16) -> SaveResult<SymbolDatabase>,
+    on_progress: impl FnMut(usize, usize),
+) -> SaveResult<()> {
+    apply_migration_plan_steps(plan, save, symbols_for, on_progress)
+}
+
+fn with_rollback(
+    save: &mut SaveBinary,
+    f: impl FnOnce(&mut SaveBinary) -> SaveResult<()>,
+) -> SaveResult<()> {
+    let snapshot = save.clone();
+    if let Err(e) = f(save) {
+        save.restore_bytes_from(&snapshot);
+        return Err(e);
+    }
+
+    Ok(())
+}
+
+fn apply_migration_plan_steps(
+    plan: &PatchPlan,
+    save: &mut SaveBinary,
+    symbols_for: impl Fn(u16) -> SaveResult<SymbolDatabase>,
+    mut on_progress: impl FnMut(usize, usize),
+) -> SaveResult<()> {
+    let total = plan.len();
+    for (index, patch) in plan.iter().enumerate() {
+        apply_plan_step(plan, *patch, save, &symbols_for)?;
+        on_progress(index + 1, total);
+    }
+
+    Ok(())
+}
+
+/// Resolves a sequence of migration patches that downgrade a save to `target_version`.
+///
+/// This mirrors [`resolve_migration_plan`] in the opposite direction: the plan is built by
+/// repeatedly finding a migration patch whose `to_version` matches the current step and whose
+/// `from_version` is lower. The returned patches are in application order; the plan functions
+/// such as [`apply_migration_plan_atomic`] apply each one with [`Patch::apply_reverse`].
+///
+/// # Errors
+/// Returns an error if `current_version` is older than `target_version`, if a required step is
+/// missing, or if the migration graph contains a cycle along the path.
+pub fn resolve_downgrade_plan(
+    migrations: &[&'static dyn Patch],
+    current_version: u16,
+    target_version: u16,
+) -> SaveResult<PatchPlan> {
+    if current_version == target_version {
+        return Ok(PatchPlan::downgrade(Vec::new()));
+    }
+
+    if current_version < target_version {
+        return Err(SaveError::UnsupportedMigrationDirection {
+            current_version,
+            target_version,
+        });
+    }
+
+    let mut plan: Vec<&'static dyn Patch> = Vec::new();
+    let mut visited: HashSet<u16> = HashSet::from([current_version]);
+    let mut v = current_version;
+
+    while v != target_version {
+        let next = migrations.iter().find(|p| {
+            let meta = p.metadata();
+            meta.kind == PatchKind::Migration
+                && meta.to_version == Some(v)
+                && meta.from_version.is_some_and(|from| from < v)
+        });
+
+        let Some(patch) = next else {
+            return Err(SaveError::MissingMigrationStep {
+                from_version: v,
+                target_version,
+            });
+        };
+
+        let meta = patch.metadata();
+        let from = meta.from_version.expect("validated above");
+        if !visited.insert(from) {
+            return Err(migration_cycle_error(from));
+        }
+        plan.push(*patch);
+        v = from;
+    }
+
+    Ok(PatchPlan::downgrade(plan))
 }
 
 #[cfg(test)]
@@ -228,6 +1373,8 @@ mod tests {
             kind: PatchKind::Fix,
             from_version: None,
             to_version: None,
+            description: "",
+            display_name: None,
         },
     };
     static M7_TO_8: DummyPatch = DummyPatch {
@@ -236,6 +1383,8 @@ mod tests {
             kind: PatchKind::Migration,
             from_version: Some(7),
             to_version: Some(8),
+            description: "",
+            display_name: None,
         },
     };
     static M8_TO_9: DummyPatch = DummyPatch {
@@ -244,6 +1393,8 @@ mod tests {
             kind: PatchKind::Migration,
             from_version: Some(8),
             to_version: Some(9),
+            description: "",
+            display_name: None,
         },
     };
     static M9_TO_10: DummyPatch = DummyPatch {
@@ -252,9 +1403,662 @@ mod tests {
             kind: PatchKind::Migration,
             from_version: Some(9),
             to_version: Some(10),
+            description: "",
+            display_name: None,
         },
     };
 
+    #[derive(Debug)]
+    struct WriteThenFailPatch {
+        meta: PatchMetadata,
+        fail: bool,
+    }
+
+    impl Patch for WriteThenFailPatch {
+        fn metadata(&self) -> PatchMetadata {
+            self.meta
+        }
+
+        fn apply(&self, save: &mut SaveBinary, _symbols: &SymbolDatabase) -> SaveResult<()> {
+            let version = self.meta.to_version.unwrap_or(0xFF) as u8;
+            save.write_u8(crate::Address(0), version)?;
+            if self.fail {
+                return Err(SaveError::NotImplemented {
+                    feature: self.meta.id.to_string(),
+                });
+            }
+            Ok(())
+        }
+
+        fn apply_reverse(
+            &self,
+            save: &mut SaveBinary,
+            _symbols: &SymbolDatabase,
+        ) -> SaveResult<()> {
+            let version = self.meta.from_version.unwrap_or(0xFF) as u8;
+            save.write_u8(crate::Address(0), version)
+        }
+    }
+
+    static WRITE_7_TO_8: WriteThenFailPatch = WriteThenFailPatch {
+        meta: PatchMetadata {
+            id: "write_7_to_8",
+            kind: PatchKind::Migration,
+            from_version: Some(7),
+            to_version: Some(8),
+            description: "",
+            display_name: None,
+        },
+        fail: false,
+    };
+    static FAIL_8_TO_9: WriteThenFailPatch = WriteThenFailPatch {
+        meta: PatchMetadata {
+            id: "fail_8_to_9",
+            kind: PatchKind::Migration,
+            from_version: Some(8),
+            to_version: Some(9),
+            description: "",
+            display_name: None,
+        },
+        fail: true,
+    };
+
+    #[derive(Debug)]
+    struct DoubleFirstBytePatch;
+
+    impl Patch for DoubleFirstBytePatch {
+        fn metadata(&self) -> PatchMetadata {
+            M8_TO_9.meta
+        }
+
+        fn apply(&self, save: &mut SaveBinary, _symbols: &SymbolDatabase) -> SaveResult<()> {
+            let value = save.read_u8(crate::Address(0))?;
+            save.write_u8(crate::Address(0), value * 2)
+        }
+    }
+
+    static DOUBLE_FIRST_BYTE: DoubleFirstBytePatch = DoubleFirstBytePatch;
+
+    fn first_byte_is_seven(save: &SaveBinary) -> bool {
+        save.read_u8(crate::Address(0)).is_ok_and(|b| b == 7)
+    }
+
+    #[test]
+    fn conditional_patch_skips_when_condition_is_false() {
+        let patch = ConditionalPatch::new(DoubleFirstBytePatch, first_byte_is_seven);
+        let mut save = SaveBinary::new(vec![3]);
+        let mut log = VecPatchLogSink::new();
+        patch
+            .apply_with_log(&mut save, &SymbolDatabase::new(), &mut log)
+            .unwrap();
+        assert_eq!(save.as_bytes(), &[3]);
+
+        let entries = log.into_entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].level, PatchLogLevel::Info);
+        assert_eq!(entries[0].source, "m8_to_9");
+    }
+
+    #[test]
+    fn conditional_patch_applies_when_condition_is_true() {
+        let patch = ConditionalPatch::new(DoubleFirstBytePatch, first_byte_is_seven);
+        let mut save = SaveBinary::new(vec![7]);
+        patch.apply(&mut save, &SymbolDatabase::new()).unwrap();
+        assert_eq!(save.as_bytes(), &[14]);
+        assert_eq!(patch.metadata().id, "m8_to_9");
+    }
+
+    #[test]
+    fn conditional_patch_propagates_inner_error() {
+        let patch = ConditionalPatch::new(
+            WriteThenFailPatch {
+                meta: FAIL_8_TO_9.meta,
+                fail: true,
+            },
+            first_byte_is_seven,
+        );
+        let mut save = SaveBinary::new(vec![7]);
+        let err = patch.apply(&mut save, &SymbolDatabase::new()).unwrap_err();
+        assert!(matches!(err, SaveError::NotImplemented { .. }));
+    }
+
+    #[test]
+    fn apply_and_diff_is_empty_for_noop_patch() {
+        let mut save = SaveBinary::new(vec![1, 2, 3]);
+        let diff = apply_and_diff(&M7_TO_8, &mut save, &SymbolDatabase::new()).unwrap();
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn apply_and_diff_reports_single_byte_write() {
+        let mut save = SaveBinary::new(vec![7, 2, 3]);
+        let diff = apply_and_diff(&WRITE_7_TO_8, &mut save, &SymbolDatabase::new()).unwrap();
+        assert_eq!(diff, vec![(crate::Address(0), 7, 8)]);
+    }
+
+    #[test]
+    fn progress_is_reported_once_per_step() {
+        let mut save = SaveBinary::new(vec![7, 1]);
+        let plan = PatchPlan::new(vec![&WRITE_7_TO_8, &DOUBLE_FIRST_BYTE]);
+        let mut calls = Vec::new();
+        apply_migration_plan_with_progress(
+            &plan,
+            &mut save,
+            |_| Ok(SymbolDatabase::new()),
+            |done, total| calls.push((done, total)),
+        )
+        .unwrap();
+        assert_eq!(calls, vec![(1, 2), (2, 2)]);
+        assert_eq!(save.as_bytes(), &[16, 1]);
+    }
+
+    #[test]
+    fn apply_atomic_restores_bytes_on_failure() {
+        let mut save = SaveBinary::new(vec![7, 1, 2]);
+        let err = apply_atomic(&FAIL_8_TO_9, &mut save, &SymbolDatabase::new()).unwrap_err();
+        assert!(matches!(err, SaveError::NotImplemented { .. }));
+        assert_eq!(save.as_bytes(), &[7, 1, 2]);
+    }
+
+    #[test]
+    fn apply_atomic_keeps_changes_on_success() {
+        let mut save = SaveBinary::new(vec![7, 1, 2]);
+        apply_atomic(&WRITE_7_TO_8, &mut save, &SymbolDatabase::new()).unwrap();
+        assert_eq!(save.as_bytes(), &[8, 1, 2]);
+    }
+
+    #[test]
+    fn apply_or_rollback_restores_bytes_and_logs() {
+        let mut save = SaveBinary::new(vec![7, 1, 2]);
+        let mut log = VecPatchLogSink::new();
+        let err = apply_or_rollback(&FAIL_8_TO_9, &mut save, &SymbolDatabase::new(), &mut log)
+            .unwrap_err();
+        assert!(matches!(err, SaveError::NotImplemented { .. }));
+        assert_eq!(save.as_bytes(), &[7, 1, 2]);
+
+        let entries = log.into_entries();
+        let last = entries.last().unwrap();
+        assert_eq!(last.level, PatchLogLevel::Warning);
+        assert_eq!(last.source, FAIL_8_TO_9.meta.id);
+    }
+
+    #[test]
+    fn apply_or_rollback_keeps_changes_on_success() {
+        let mut save = SaveBinary::new(vec![7, 1, 2]);
+        let mut log = VecPatchLogSink::new();
+        apply_or_rollback(&WRITE_7_TO_8, &mut save, &SymbolDatabase::new(), &mut log).unwrap();
+        assert_eq!(save.as_bytes(), &[8, 1, 2]);
+        assert!(log.into_entries().is_empty());
+    }
+
+    #[test]
+    fn apply_migration_plan_atomic_rolls_back_all_steps() {
+        let mut save = SaveBinary::new(vec![7, 1, 2]);
+        let plan = PatchPlan::new(vec![&WRITE_7_TO_8, &FAIL_8_TO_9]);
+        let err = apply_migration_plan_atomic(&plan, &mut save, |_| Ok(SymbolDatabase::new()))
+            .unwrap_err();
+        assert!(matches!(err, SaveError::NotImplemented { .. }));
+        assert_eq!(save.as_bytes(), &[7, 1, 2]);
+    }
+
+    #[test]
+    fn apply_migration_plan_atomic_keeps_recording_on_rollback() {
+        let mut save = SaveBinary::new(vec![7, 1, 2]).into_recording();
+        let plan = PatchPlan::new(vec![&WRITE_7_TO_8, &FAIL_8_TO_9]);
+        apply_migration_plan_atomic(&plan, &mut save, |_| Ok(SymbolDatabase::new())).unwrap_err();
+
+        assert_eq!(save.as_bytes(), &[7, 1, 2]);
+        let writes = save.writes();
+        assert_eq!(
+            writes,
+            &[
+                (crate::Address(0), 7, 8),
+                (crate::Address(0), 8, 9),
+                (crate::Address(0), 9, 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn apply_migration_plan_atomic_reverses_downgrade_plan() {
+        static W7_TO_8: WriteThenFailPatch = WriteThenFailPatch {
+            meta: WRITE_7_TO_8.meta,
+            fail: false,
+        };
+        static W8_TO_9: WriteThenFailPatch = WriteThenFailPatch {
+            meta: FAIL_8_TO_9.meta,
+            fail: false,
+        };
+        static W9_TO_10: WriteThenFailPatch = WriteThenFailPatch {
+            meta: M9_TO_10.meta,
+            fail: false,
+        };
+        let migrations: [&'static dyn Patch; 3] = [&W7_TO_8, &W8_TO_9, &W9_TO_10];
+        let plan = resolve_downgrade_plan(&migrations, 10, 7).unwrap();
+
+        let mut save = SaveBinary::new(vec![10]).into_recording();
+        let read_versions = std::cell::RefCell::new(Vec::new());
+        apply_migration_plan_atomic(&plan, &mut save, |version| {
+            read_versions.borrow_mut().push(version);
+            Ok(SymbolDatabase::new())
+        })
+        .unwrap();
+
+        assert_eq!(save.as_bytes(), &[7]);
+        assert_eq!(read_versions.into_inner(), vec![10, 9, 8]);
+        assert_eq!(
+            save.writes(),
+            &[
+                (crate::Address(0), 10, 9),
+                (crate::Address(0), 9, 8),
+                (crate::Address(0), 8, 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_plan_reports_failing_step_without_mutating() {
+        let save = SaveBinary::new(vec![7, 1, 2]);
+        let plan = PatchPlan::new(vec![&WRITE_7_TO_8, &FAIL_8_TO_9]);
+        let err = validate_migration_plan(&plan, &save, |_| Ok(SymbolDatabase::new())).unwrap_err();
+        match err {
+            SaveError::PatchFailed { id, source } => {
+                assert_eq!(id, "fail_8_to_9");
+                assert!(matches!(*source, SaveError::NotImplemented { .. }));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert_eq!(save.as_bytes(), &[7, 1, 2]);
+    }
+
+    #[test]
+    fn validate_plan_accepts_empty_and_passing_plans() {
+        let save = SaveBinary::new(vec![7]);
+        validate_migration_plan(&PatchPlan::default(), &save, |_| Ok(SymbolDatabase::new()))
+            .unwrap();
+
+        let plan = PatchPlan::new(vec![&WRITE_7_TO_8]);
+        validate_migration_plan(&plan, &save, |_| Ok(SymbolDatabase::new())).unwrap();
+        assert_eq!(save.as_bytes(), &[7]);
+    }
+
+    #[test]
+    fn registry_rejects_duplicate_ids() {
+        static DUP_7_TO_8: DummyPatch = DummyPatch {
+            meta: PatchMetadata {
+                id: "m7_to_8",
+                kind: PatchKind::Migration,
+                from_version: Some(7),
+                to_version: Some(8),
+                description: "",
+                display_name: None,
+            },
+        };
+
+        let mut registry = PatchRegistry::new();
+        registry.register(&M7_TO_8).unwrap();
+        let err = registry.register(&DUP_7_TO_8).unwrap_err();
+        match err {
+            SaveError::DuplicatePatchId { id } => assert_eq!(id, "m7_to_8"),
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert_eq!(registry.iter().count(), 1);
+    }
+
+    #[test]
+    fn registry_looks_up_and_filters_by_kind() {
+        let mut registry = PatchRegistry::new();
+        for patch in [&FIX as &'static dyn Patch, &M7_TO_8, &M8_TO_9] {
+            registry.register(patch).unwrap();
+        }
+
+        assert_eq!(registry.lookup("m8_to_9").unwrap().metadata().id, "m8_to_9");
+        assert!(registry.lookup("missing").is_none());
+
+        let migrations: Vec<&'static str> = registry
+            .all_migrations()
+            .iter()
+            .map(|p| p.metadata().id)
+            .collect();
+        assert_eq!(migrations, vec!["m7_to_8", "m8_to_9"]);
+
+        let fixes: Vec<&'static str> = registry
+            .all_fixes()
+            .iter()
+            .map(|p| p.metadata().id)
+            .collect();
+        assert_eq!(fixes, vec!["fix"]);
+    }
+
+    #[test]
+    fn counting_sink_tallies_by_level() {
+        let mut sink = CountingPatchLogSink::new();
+        sink.info("a", "one");
+        sink.info("a", "two");
+        sink.warn("b", "three");
+        assert_eq!(sink.info_count, 2);
+        assert_eq!(sink.warn_count, 1);
+        assert_eq!(sink.error_count, 0);
+        assert_eq!(sink.total(), 3);
+        assert!(!sink.has_errors());
+
+        sink.error("c", "four");
+        assert_eq!(sink.total(), 4);
+        assert!(sink.has_errors());
+    }
+
+    #[test]
+    fn write_sink_formats_lines_and_filters_by_level() {
+        let mut sink = WritePatchLogSink::new(Vec::new(), PatchLogLevel::Warning);
+        sink.info("a", "hidden");
+        sink.warn("b", "careful");
+        sink.error("c", "broken");
+
+        let out = String::from_utf8(sink.into_inner()).unwrap();
+        assert_eq!(out, "[warn] b: careful\n[error] c: broken\n");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn log_entry_serde_round_trip() {
+        let entry = PatchLogEntry::warning("m7_to_8", "bit 3 has no mapping");
+        let json = serde_json::to_string(&entry).unwrap();
+        assert_eq!(
+            json,
+            r#"{"level":"warn","source":"m7_to_8","message":"bit 3 has no mapping"}"#
+        );
+
+        let parsed: PatchLogEntry = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, entry);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn vec_sink_entries_serde_round_trip() {
+        let mut sink = VecPatchLogSink::new();
+        sink.info("m7_to_8", "moved bag");
+        sink.error("m8_to_9", "missing symbol");
+        let entries = sink.into_entries();
+
+        let json = serde_json::to_string(&entries).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"level":"info","source":"m7_to_8","message":"moved bag"},{"level":"error","source":"m8_to_9","message":"missing symbol"}]"#
+        );
+
+        let parsed: Vec<PatchLogEntry> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, entries);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn log_level_serializes_as_label() {
+        for level in [
+            PatchLogLevel::Info,
+            PatchLogLevel::Warning,
+            PatchLogLevel::Error,
+        ] {
+            let json = serde_json::to_string(&level).unwrap();
+            assert_eq!(json, format!("\"{}\"", level.as_str()));
+            assert_eq!(serde_json::from_str::<PatchLogLevel>(&json).unwrap(), level);
+        }
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn log_crate_sink_without_logger_is_noop() {
+        let mut sink = LogCrateSink::new("gb_save_core::test");
+        sink.info("a", "one");
+        sink.warn("b", "two");
+        sink.error("c", "three");
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn log_crate_sink_forwards_to_env_logger() {
+        let _ = env_logger::builder()
+            .is_test(true)
+            .filter_level(log::LevelFilter::Info)
+            .try_init();
+
+        let mut sink = LogCrateSink {
+            target: "gb_save_core::test",
+        };
+        assert!(log::log_enabled!(target: sink.target, log::Level::Info));
+        sink.info("a", "one");
+        sink.error("c", "three");
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn tracing_sink_logs_through_global_fmt_subscriber() {
+        let _ = tracing_subscriber::fmt::try_init();
+        let mut sink = TracingSink;
+        sink.info("a", "one");
+        sink.warn("b", "two");
+        sink.error("c", "three");
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn tracing_sink_emits_events_at_entry_level() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone, Default)]
+        struct Capture(Arc<Mutex<Vec<u8>>>);
+
+        impl io::Write for Capture {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let capture = Capture::default();
+        let writer = capture.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .without_time()
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let mut sink = TracingSink;
+            sink.info("m7_to_8", "moved");
+            sink.warn("m7_to_8", "careful");
+            sink.error("m8_to_9", "broken");
+        });
+
+        let out = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 3, "{out}");
+        assert!(lines[0].contains("INFO patch{id=\"m7_to_8\"}"), "{out}");
+        assert!(lines[0].contains("patch.message=\"moved\""), "{out}");
+        assert!(lines[1].contains("WARN"), "{out}");
+        assert!(lines[1].contains("patch.source=\"m7_to_8\""), "{out}");
+        assert!(lines[2].contains("ERROR"), "{out}");
+        assert!(lines[2].contains("patch.message=\"broken\""), "{out}");
+    }
+
+    #[test]
+    fn dedup_sink_caps_identical_entries() {
+        let mut sink = VecPatchLogSinkDedup::new(3);
+        for _ in 0..100 {
+            sink.warn("remap", "bit 5 is unmapped");
+        }
+
+        let entries = sink.into_entries();
+        assert_eq!(entries.len(), 4);
+        assert!(entries[..3]
+            .iter()
+            .all(|e| e.message == "bit 5 is unmapped"));
+        assert_eq!(entries[3].level, PatchLogLevel::Warning);
+        assert_eq!(entries[3].source, "remap");
+        assert_eq!(
+            entries[3].message,
+            "bit 5 is unmapped ... (suppressed 97 duplicates)"
+        );
+    }
+
+    #[test]
+    fn dedup_sink_with_zero_max_keeps_one_summary() {
+        let mut sink = VecPatchLogSinkDedup::new(0);
+        for _ in 0..100 {
+            sink.warn("remap", "bit 5 is unmapped");
+        }
+        sink.warn("remap", "bit 6 is unmapped");
+
+        let messages: Vec<String> = sink.into_entries().into_iter().map(|e| e.message).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "bit 5 is unmapped ... (suppressed 99 duplicates)",
+                "bit 6 is unmapped",
+            ]
+        );
+    }
+
+    #[test]
+    fn dedup_sink_tracks_each_entry_separately() {
+        let mut sink = VecPatchLogSinkDedup::new(1);
+        sink.warn("remap", "a");
+        sink.warn("remap", "a");
+        sink.info("remap", "a");
+        sink.warn("other", "a");
+        sink.warn("remap", "b");
+
+        let messages: Vec<String> = sink.into_entries().into_iter().map(|e| e.message).collect();
+        assert_eq!(
+            messages,
+            vec!["a", "a ... (suppressed 1 duplicate)", "a", "a", "b"]
+        );
+    }
+
+    #[test]
+    fn metadata_builder_fills_fields() {
+        const META: PatchMetadata = PatchMetadata::builder()
+            .id("m7_to_8")
+            .migration(7, 8)
+            .display_name("Seven to eight")
+            .build();
+        assert_eq!(
+            META,
+            PatchMetadata {
+                id: "m7_to_8",
+                kind: PatchKind::Migration,
+                from_version: Some(7),
+                to_version: Some(8),
+                description: "",
+                display_name: Some("Seven to eight"),
+            }
+        );
+
+        let fix = PatchMetadata::builder()
+            .id("fix")
+            .migration(1, 2)
+            .fix()
+            .description("repairs")
+            .build();
+        assert_eq!(fix.kind, PatchKind::Fix);
+        assert_eq!((fix.from_version, fix.to_version), (None, None));
+        assert_eq!(fix.description, "repairs");
+        assert_eq!(fix.label(), "fix");
+    }
+
+    #[test]
+    #[should_panic(expected = "without an id")]
+    fn metadata_builder_requires_id() {
+        let _ = PatchMetadataBuilder::new().migration(1, 2).build();
+    }
+
+    #[test]
+    fn write_sink_uses_custom_format() {
+        let mut sink = WritePatchLogSink::with_format(Vec::new(), PatchLogLevel::Info, |e| {
+            format!("{}|{}", e.source, e.message)
+        });
+        sink.info("a", "one");
+
+        assert_eq!(String::from_utf8(sink.into_inner()).unwrap(), "a|one\n");
+    }
+
+    #[test]
+    fn tee_forwards_to_both_and_filter_drops_low_levels() {
+        let mut everything = VecPatchLogSink::new();
+        let warnings = FilteredPatchLogSink::new(VecPatchLogSink::new(), PatchLogLevel::Warning);
+        let mut tee = TeePatchLogSink::new(&mut everything, warnings);
+        tee.info("a", "one");
+        tee.warn("b", "two");
+        tee.error("c", "three");
+
+        let (_, warnings) = tee.into_inner();
+        let warnings = warnings.into_inner().into_entries();
+        assert_eq!(everything.into_entries().len(), 3);
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|e| e.source.as_ref())
+                .collect::<Vec<_>>(),
+            vec!["b", "c"]
+        );
+    }
+
+    #[test]
+    fn metadata_label_falls_back_to_id() {
+        assert_eq!(FIX.metadata().label(), "fix");
+
+        let named = PatchMetadata {
+            display_name: Some("Fix things"),
+            ..FIX.metadata()
+        };
+        assert_eq!(named.label(), "Fix things");
+    }
+
+    #[test]
+    fn composite_applies_steps_in_order() {
+        static COMPOSITE: PatchComposite = PatchComposite::new(
+            PatchMetadata {
+                id: "composite",
+                kind: PatchKind::Migration,
+                from_version: Some(7),
+                to_version: Some(8),
+                description: "",
+                display_name: None,
+            },
+            &[&WRITE_7_TO_8, &DOUBLE_FIRST_BYTE],
+        );
+
+        let mut save = SaveBinary::new(vec![7, 1]);
+        COMPOSITE.apply(&mut save, &SymbolDatabase::new()).unwrap();
+        assert_eq!(save.as_bytes(), &[16, 1]);
+    }
+
+    #[test]
+    fn composite_rolls_back_on_partial_failure() {
+        static COMPOSITE: PatchComposite = PatchComposite::new(
+            PatchMetadata {
+                id: "composite",
+                kind: PatchKind::Migration,
+                from_version: Some(7),
+                to_version: Some(9),
+                description: "",
+                display_name: None,
+            },
+            &[&WRITE_7_TO_8, &FAIL_8_TO_9],
+        );
+
+        let mut save = SaveBinary::new(vec![7, 1]);
+        let mut log = VecPatchLogSink::new();
+        let err = COMPOSITE
+            .apply_with_log(&mut save, &SymbolDatabase::new(), &mut log)
+            .unwrap_err();
+        assert!(matches!(err, SaveError::NotImplemented { .. }));
+        assert_eq!(save.as_bytes(), &[7, 1]);
+    }
+
     #[test]
     fn resolve_plan_returns_empty_when_already_at_target() {
         let migrations: [&'static dyn Patch; 0] = [];
@@ -280,18 +2084,183 @@ mod tests {
 
     #[test]
     fn resolve_plan_errors_when_step_is_missing() {
-        let migrations: [&'static dyn Patch; 1] = [&M7_TO_8];
-        let err = resolve_migration_plan(&migrations, 7, 9).unwrap_err();
+        let migrations: [&'static dyn Patch; 2] = [&M7_TO_8, &M9_TO_10];
+        let err = resolve_migration_plan(&migrations, 7, 10).unwrap_err();
         match err {
             SaveError::MissingMigrationStep {
                 from_version,
                 target_version,
             } => {
                 assert_eq!(from_version, 8);
-                assert_eq!(target_version, 9);
+                assert_eq!(target_version, 10);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn resolve_plan_respects_max_steps() {
+        static M10_TO_11: DummyPatch = DummyPatch {
+            meta: PatchMetadata::builder()
+                .id("m10_to_11")
+                .migration(10, 11)
+                .build(),
+        };
+        static M11_TO_12: DummyPatch = DummyPatch {
+            meta: PatchMetadata::builder()
+                .id("m11_to_12")
+                .migration(11, 12)
+                .build(),
+        };
+
+        let migrations: [&'static dyn Patch; 5] =
+            [&M7_TO_8, &M8_TO_9, &M9_TO_10, &M10_TO_11, &M11_TO_12];
+
+        let err = resolve_migration_plan_with_max_steps(&migrations, 7, 12, 3).unwrap_err();
+        match err {
+            SaveError::InvalidSaveState { reason } => {
+                assert_eq!(reason, "migration plan exceeds max_steps (3)");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        let plan = resolve_migration_plan_with_max_steps(&migrations, 7, 10, 3).unwrap();
+        assert_eq!(plan.len(), 3);
+        assert_eq!(resolve_migration_plan(&migrations, 7, 12).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn resolve_plan_errors_when_version_is_unknown() {
+        let migrations: [&'static dyn Patch; 3] = [&FIX, &M7_TO_8, &M8_TO_9];
+        let err = resolve_migration_plan(&migrations, 7, 11).unwrap_err();
+        match &err {
+            SaveError::VersionNotSupported { version, supported } => {
+                assert_eq!(*version, 11);
+                assert_eq!(supported, &vec![7, 8, 9]);
             }
             other => panic!("unexpected error: {other:?}"),
         }
+        assert_eq!(
+            err.to_string(),
+            "unsupported save version 11 (supported: 7, 8, 9)"
+        );
+
+        let err = resolve_migration_plan(&migrations, 5, 9).unwrap_err();
+        assert!(matches!(
+            err,
+            SaveError::VersionNotSupported { version: 5, .. }
+        ));
+    }
+
+    #[test]
+    fn resolve_downgrade_plan_returns_reverse_chain() {
+        let migrations: [&'static dyn Patch; 4] = [&FIX, &M7_TO_8, &M8_TO_9, &M9_TO_10];
+        let plan = resolve_downgrade_plan(&migrations, 10, 7).unwrap();
+        let ids: Vec<&'static str> = plan.iter().map(|p| p.metadata().id).collect();
+        assert_eq!(ids, vec!["m9_to_10", "m8_to_9", "m7_to_8"]);
+    }
+
+    #[test]
+    fn resolve_downgrade_plan_errors_when_step_is_missing() {
+        let migrations: [&'static dyn Patch; 2] = [&M7_TO_8, &M9_TO_10];
+        let err = resolve_downgrade_plan(&migrations, 10, 7).unwrap_err();
+        match err {
+            SaveError::MissingMigrationStep {
+                from_version,
+                target_version,
+            } => {
+                assert_eq!(from_version, 9);
+                assert_eq!(target_version, 7);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn resolve_downgrade_plan_errors_when_direction_is_upgrade() {
+        let migrations: [&'static dyn Patch; 0] = [];
+        let err = resolve_downgrade_plan(&migrations, 7, 8).unwrap_err();
+        assert!(matches!(
+            err,
+            SaveError::UnsupportedMigrationDirection { .. }
+        ));
+    }
+
+    #[test]
+    fn apply_reverse_defaults_to_not_implemented() {
+        let mut save = SaveBinary::new(vec![0]);
+        let err = M7_TO_8
+            .apply_reverse(&mut save, &SymbolDatabase::new())
+            .unwrap_err();
+        assert!(matches!(err, SaveError::NotImplemented { .. }));
+    }
+
+    #[test]
+    fn resolve_plan_ignores_migrations_back_to_visited_versions() {
+        static M8_TO_7: DummyPatch = DummyPatch {
+            meta: PatchMetadata {
+                id: "m8_to_7",
+                kind: PatchKind::Migration,
+                from_version: Some(8),
+                to_version: Some(7),
+                description: "",
+                display_name: None,
+            },
+        };
+
+        let migrations: [&'static dyn Patch; 3] = [&M7_TO_8, &M8_TO_7, &M8_TO_9];
+        let plan = resolve_migration_plan(&migrations, 7, 9).unwrap();
+        assert_eq!(plan.ids(), vec!["m7_to_8", "m8_to_9"]);
+
+        let plan = resolve_downgrade_plan(&migrations, 9, 7).unwrap();
+        assert_eq!(plan.ids(), vec!["m8_to_9", "m7_to_8"]);
+    }
+
+    #[test]
+    fn resolve_plan_ignores_self_loop() {
+        static M8_TO_8: DummyPatch = DummyPatch {
+            meta: PatchMetadata {
+                id: "m8_to_8",
+                kind: PatchKind::Migration,
+                from_version: Some(8),
+                to_version: Some(8),
+                description: "",
+                display_name: None,
+            },
+        };
+
+        let migrations: [&'static dyn Patch; 3] = [&M7_TO_8, &M8_TO_8, &M8_TO_9];
+        let plan = resolve_migration_plan(&migrations, 7, 9).unwrap();
+        assert_eq!(plan.ids(), vec!["m7_to_8", "m8_to_9"]);
+
+        let plan = resolve_downgrade_plan(&migrations, 9, 7).unwrap();
+        assert_eq!(plan.ids(), vec!["m8_to_9", "m7_to_8"]);
+    }
+
+    #[test]
+    fn plan_display_shows_version_chain() {
+        let migrations: [&'static dyn Patch; 3] = [&M7_TO_8, &M8_TO_9, &M9_TO_10];
+        let plan = resolve_migration_plan(&migrations, 7, 9).unwrap();
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan.ids(), vec!["m7_to_8", "m8_to_9"]);
+        assert_eq!(plan.to_string(), "v7 → v8 (m7_to_8) → v9 (m8_to_9)");
+
+        let plan = resolve_downgrade_plan(&migrations, 10, 8).unwrap();
+        assert!(plan.is_downgrade());
+        assert_eq!(plan.to_string(), "v10 → v9 (m9_to_10) → v8 (m8_to_9)");
+
+        assert_eq!(PatchPlan::default().to_string(), "");
+    }
+
+    #[test]
+    fn migration_graph_dot_lists_nodes_and_edges() {
+        let migrations: [&dyn Patch; 3] = [&FIX, &M7_TO_8, &M8_TO_9];
+        let dot = migration_graph_to_dot(&migrations);
+        assert!(dot.contains("digraph"));
+        assert!(dot.contains("    v7 -> v8 [label=\"m7_to_8\"];\n"));
+        assert!(dot.contains("    v8 -> v9 [label=\"m8_to_9\"];\n"));
+        assert!(dot.contains("    v9 [label=\"v9\"];\n"));
+        assert!(!dot.contains("fix"));
     }
 
     #[test]
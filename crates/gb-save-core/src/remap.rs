@@ -1,4 +1,4 @@
-use crate::{Address, SaveBinary, SaveResult};
+use crate::{Address, AddressRange, PatchLogSink, SaveBinary, SaveError, SaveResult};
 
 #[allow(clippy::too_many_arguments)]
 /// Copies set bits from one bitset to another using an index mapping.
@@ -12,6 +12,101 @@ use crate::{Address, SaveBinary, SaveResult};
 /// # Errors
 /// Returns an error if the source/destination bit addresses are out of bounds.
 pub fn map_bitset(
+    source: &SaveBinary,
+    src_base: Address,
+    src_bits: usize,
+    dest: &mut SaveBinary,
+    dst_base: Address,
+    dst_bits: usize,
+    map_index: impl FnMut(usize) -> Option<usize>,
+    on_unmapped: impl FnMut(usize),
+) -> SaveResult<()> {
+    map_bitset_counting(
+        source,
+        src_base,
+        src_bits,
+        dest,
+        dst_base,
+        dst_bits,
+        map_index,
+        on_unmapped,
+    )
+    .map(|_| ())
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Like [`map_bitset`], but returns `(mapped, unmapped)` counts of set source bits instead of
+/// reporting each unmapped bit.
+///
+/// # Errors
+/// Returns an error if the source/destination bit addresses are out of bounds.
+pub fn remap_bitset_counted(
+    source: &SaveBinary,
+    src_base: Address,
+    src_bits: usize,
+    dest: &mut SaveBinary,
+    dst_base: Address,
+    dst_bits: usize,
+    map_index: impl FnMut(usize) -> Option<usize>,
+) -> SaveResult<(usize, usize)> {
+    map_bitset_counting(
+        source,
+        src_base,
+        src_bits,
+        dest,
+        dst_base,
+        dst_bits,
+        map_index,
+        |_| {},
+    )
+}
+
+/// Copies individual bits: source bit `src_indices[i]` is written to destination bit
+/// `dst_indices[i]`.
+///
+/// Unlike [`map_bitset`], unset source bits are copied too (clearing the destination bit), and
+/// only the listed bits are read.
+///
+/// # Errors
+/// Returns [`SaveError::InvalidSaveState`] if the index slices differ in length (before anything is
+/// written), or an error if any bit address is out of bounds.
+///
+/// # Example
+/// ```
+/// use gb_save_core::{remap_sparse_bitset, Address, SaveBinary};
+///
+/// let src = SaveBinary::new(vec![0b0010_0101]);
+/// let mut dst = SaveBinary::new(vec![0]);
+/// remap_sparse_bitset(&src, Address(0), &[0, 2, 5], &mut dst, Address(0), &[1, 3, 7]).unwrap();
+/// assert_eq!(dst.read_u8(Address(0)).unwrap(), 0b1000_1010);
+/// ```
+pub fn remap_sparse_bitset(
+    source: &SaveBinary,
+    src_base: Address,
+    src_indices: &[usize],
+    dest: &mut SaveBinary,
+    dst_base: Address,
+    dst_indices: &[usize],
+) -> SaveResult<()> {
+    if src_indices.len() != dst_indices.len() {
+        return Err(SaveError::InvalidSaveState {
+            reason: format!(
+                "sparse bitset index lists differ in length ({} source, {} destination)",
+                src_indices.len(),
+                dst_indices.len()
+            ),
+        });
+    }
+
+    for (&src_index, &dst_index) in src_indices.iter().zip(dst_indices) {
+        let value = source.read_indexed_bit(src_base, src_index)?;
+        dest.write_indexed_bit(dst_base, dst_index, value)?;
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn map_bitset_counting(
     source: &SaveBinary,
     src_base: Address,
     src_bits: usize,
@@ -20,26 +115,60 @@ pub fn map_bitset(
     dst_bits: usize,
     mut map_index: impl FnMut(usize) -> Option<usize>,
     mut on_unmapped: impl FnMut(usize),
-) -> SaveResult<()> {
+) -> SaveResult<(usize, usize)> {
+    let (mut mapped, mut unmapped) = (0, 0);
     for src_index in 0..src_bits {
         if !source.read_indexed_bit(src_base, src_index)? {
             continue;
         }
 
-        let Some(dst_index) = map_index(src_index) else {
+        let Some(dst_index) = map_index(src_index).filter(|&dst_index| dst_index < dst_bits) else {
             on_unmapped(src_index);
+            unmapped += 1;
             continue;
         };
 
-        if dst_index >= dst_bits {
-            on_unmapped(src_index);
-            continue;
-        }
-
         dest.write_indexed_bit(dst_base, dst_index, true)?;
+        mapped += 1;
     }
 
-    Ok(())
+    Ok((mapped, unmapped))
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Like [`map_bitset`], but reports each unmapped set bit as a warning in `log`.
+///
+/// Warnings use `source_name` as their source and read `bit {src_index} is set but has no
+/// mapping`.
+///
+/// # Errors
+/// Returns an error if the source/destination bit addresses are out of bounds.
+pub fn remap_bitset_with_log(
+    source: &SaveBinary,
+    src_base: Address,
+    src_bits: usize,
+    dest: &mut SaveBinary,
+    dst_base: Address,
+    dst_bits: usize,
+    map_index: impl FnMut(usize) -> Option<usize>,
+    log: &mut dyn PatchLogSink,
+    source_name: &'static str,
+) -> SaveResult<()> {
+    map_bitset(
+        source,
+        src_base,
+        src_bits,
+        dest,
+        dst_base,
+        dst_bits,
+        map_index,
+        |src_index| {
+            log.warn(
+                source_name,
+                &format!("bit {src_index} is set but has no mapping"),
+            );
+        },
+    )
 }
 
 /// Remaps a zero-terminated list of `u8` values in-place.
@@ -76,6 +205,41 @@ pub fn remap_zero_terminated_u8(
     Ok(())
 }
 
+/// Remaps a zero-terminated list of little-endian `u16` values in-place.
+///
+/// `base` points to the first entry and `max_len` is the maximum number of entries (not bytes).
+/// Iteration stops at the first `0x0000` entry. Values that cannot be mapped are left unchanged and
+/// reported via `on_invalid`.
+///
+/// # Errors
+/// Returns an error if any accessed bytes are out of bounds.
+pub fn remap_zero_terminated_u16(
+    save: &mut SaveBinary,
+    base: Address,
+    max_len: usize,
+    mut map_value: impl FnMut(u16) -> Option<u16>,
+    mut on_invalid: impl FnMut(usize, u16),
+) -> SaveResult<()> {
+    for index in 0..max_len {
+        let addr = Address(base.0 + (index * 2) as u32);
+        let value = save.read_u16_le(addr)?;
+        if value == 0 {
+            break;
+        }
+
+        let Some(mapped) = map_value(value) else {
+            on_invalid(index, value);
+            continue;
+        };
+
+        if mapped != value {
+            save.write_u16_le(addr, mapped)?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Remaps a fixed-length list of `u8` values in-place, skipping zeros.
 ///
 /// - If a value is `0`, it is left as-is.
@@ -113,6 +277,100 @@ pub fn remap_fixed_len_u8_skip_zero(
     Ok(())
 }
 
+/// Remaps a fixed-length list of little-endian `u16` values in-place, skipping zeros.
+///
+/// `len` is the number of entries; entry `i` is read from `base + 2 * i`.
+///
+/// - If a value is `0`, it is left as-is.
+/// - If `map_value` returns `None`, `on_invalid` decides a replacement value.
+///
+/// # Errors
+/// Returns an error if any accessed bytes are out of bounds.
+pub fn remap_fixed_len_u16_skip_zero(
+    save: &mut SaveBinary,
+    base: Address,
+    len: usize,
+    mut map_value: impl FnMut(u16) -> Option<u16>,
+    mut on_invalid: impl FnMut(usize, u16) -> u16,
+) -> SaveResult<()> {
+    for index in 0..len {
+        let addr = Address(base.0 + (index * 2) as u32);
+        let value = save.read_u16_le(addr)?;
+        if value == 0 {
+            continue;
+        }
+
+        let Some(mapped) = map_value(value) else {
+            let replacement = on_invalid(index, value);
+            if replacement != value {
+                save.write_u16_le(addr, replacement)?;
+            }
+            continue;
+        };
+
+        if mapped != value {
+            save.write_u16_le(addr, mapped)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Remaps every entry of a fixed-length list of little-endian `u16` values in-place.
+///
+/// Unlike [`remap_fixed_len_u16_skip_zero`], zero is treated as a real value and is passed to
+/// `map_value` like any other entry. Use this for arrays where `0` is a valid id rather than an
+/// empty slot.
+///
+/// - If `map_value` returns `None`, `on_invalid` decides a replacement value.
+///
+/// # Errors
+/// Returns an error if any accessed bytes are out of bounds.
+pub fn remap_u16_values(
+    save: &mut SaveBinary,
+    base: Address,
+    len: usize,
+    mut map_value: impl FnMut(u16) -> Option<u16>,
+    mut on_invalid: impl FnMut(usize, u16) -> u16,
+) -> SaveResult<()> {
+    for index in 0..len {
+        let addr = Address(base.0 + (index * 2) as u32);
+        let value = save.read_u16_le(addr)?;
+        let mapped = map_value(value).unwrap_or_else(|| on_invalid(index, value));
+
+        if mapped != value {
+            save.write_u16_le(addr, mapped)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Remaps a fixed-length list of `u8` values in-place using a 256-entry lookup table.
+///
+/// Every byte (including `0`) is replaced by `table[value]`. A `None` entry means the value has no
+/// mapping, and `on_invalid` decides a replacement. This avoids per-value closure calls, so game
+/// crates can declare a `static` table for large remaps.
+///
+/// # Errors
+/// Returns an error if `base..base + len` is out of bounds.
+pub fn remap_fixed_len_u8_with_lookup(
+    save: &mut SaveBinary,
+    base: Address,
+    len: usize,
+    table: &[Option<u8>; 256],
+    mut on_invalid: impl FnMut(usize, u8) -> u8,
+) -> SaveResult<()> {
+    let range = AddressRange::new(base, Address(base.0 + len as u32));
+    let mapped: Vec<u8> = save
+        .slice(range)?
+        .iter()
+        .enumerate()
+        .map(|(index, &byte)| table[usize::from(byte)].unwrap_or_else(|| on_invalid(index, byte)))
+        .collect();
+    save.write_bytes(base, &mapped)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,6 +422,60 @@ mod tests {
         assert!(!dst.read_indexed_bit(Address(0), 0).unwrap());
     }
 
+    #[test]
+    fn remap_bitset_counted_counts_mapped_and_unmapped_bits() {
+        let mut src = SaveBinary::new(vec![0u8; 2]);
+        let mut dst = SaveBinary::new(vec![0u8; 2]);
+        for index in [0, 3, 5, 9, 15] {
+            src.write_indexed_bit(Address(0), index, true).unwrap();
+        }
+
+        // Bit 3 has no mapping and bit 15 maps past `dst_bits`.
+        let counts = remap_bitset_counted(&src, Address(0), 16, &mut dst, Address(0), 12, |i| {
+            (i != 3).then_some(i - i / 8)
+        })
+        .unwrap();
+
+        assert_eq!(counts, (3, 2));
+        assert_eq!(counts.0 + counts.1, 5);
+        assert!(dst.read_indexed_bit(Address(0), 0).unwrap());
+        assert!(dst.read_indexed_bit(Address(0), 5).unwrap());
+        assert!(dst.read_indexed_bit(Address(0), 8).unwrap());
+        assert!(!dst.read_indexed_bit(Address(0), 3).unwrap());
+    }
+
+    #[test]
+    fn remap_sparse_bitset_copies_set_and_unset_bits() {
+        let src = SaveBinary::new(vec![0b0000_0101, 0x00]);
+        let mut dst = SaveBinary::new(vec![0x00, 0xFF]);
+
+        remap_sparse_bitset(
+            &src,
+            Address(0),
+            &[0, 1, 2],
+            &mut dst,
+            Address(0),
+            &[3, 8, 15],
+        )
+        .unwrap();
+
+        assert!(dst.read_indexed_bit(Address(0), 3).unwrap());
+        assert!(!dst.read_indexed_bit(Address(0), 8).unwrap());
+        assert!(dst.read_indexed_bit(Address(0), 15).unwrap());
+        assert_eq!(dst.as_bytes(), &[0b0000_1000, 0b1111_1110]);
+    }
+
+    #[test]
+    fn remap_sparse_bitset_rejects_mismatched_index_lists() {
+        let src = SaveBinary::new(vec![0xFF]);
+        let mut dst = SaveBinary::new(vec![0x00]);
+
+        let err =
+            remap_sparse_bitset(&src, Address(0), &[0, 1], &mut dst, Address(0), &[0]).unwrap_err();
+        assert!(matches!(err, SaveError::InvalidSaveState { .. }));
+        assert_eq!(dst.as_bytes(), &[0x00]);
+    }
+
     #[test]
     fn remap_fixed_len_skip_zero_keeps_zeros_and_replaces_invalid() {
         let mut save = SaveBinary::new(vec![0, 1, 2, 3]);
@@ -184,4 +496,139 @@ mod tests {
         assert_eq!(save.as_bytes(), &[0, 11, 0, 13]);
         assert_eq!(invalid, vec![(2, 2)]);
     }
+
+    #[test]
+    fn remap_fixed_len_u16_skip_zero_keeps_zeros_and_replaces_invalid() {
+        let mut save = SaveBinary::new(vec![0, 0, 1, 0, 2, 0, 0x34, 0x12]);
+        let mut invalid: Vec<(usize, u16)> = Vec::new();
+
+        remap_fixed_len_u16_skip_zero(
+            &mut save,
+            Address(0),
+            4,
+            |v| (v != 2).then_some(v + 0x100),
+            |i, v| {
+                invalid.push((i, v));
+                0
+            },
+        )
+        .unwrap();
+
+        assert_eq!(save.as_bytes(), &[0, 0, 1, 1, 0, 0, 0x34, 0x13]);
+        assert_eq!(invalid, vec![(2, 2)]);
+    }
+
+    #[test]
+    fn remap_fixed_len_u16_skip_zero_handles_entry_across_bank_boundary() {
+        let mut save = SaveBinary::new(vec![0u8; 0x4000]);
+        save.write_u16_le(Address(0x1FFF), 0x0102).unwrap();
+
+        remap_fixed_len_u16_skip_zero(&mut save, Address(0x1FFD), 2, |v| Some(v * 2), |_, v| v)
+            .unwrap();
+
+        assert_eq!(save.read_u16_le(Address(0x1FFF)).unwrap(), 0x0204);
+        assert_eq!(save.read_u16_le(Address(0x1FFD)).unwrap(), 0);
+    }
+
+    #[test]
+    fn remap_zero_terminated_u16_stops_on_zero_entry_only() {
+        let mut save = SaveBinary::new(vec![0xFF, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00]);
+        let mut invalid: Vec<(usize, u16)> = Vec::new();
+
+        remap_zero_terminated_u16(
+            &mut save,
+            Address(0),
+            4,
+            |v| (v != 2).then_some(v + 0x100),
+            |i, v| invalid.push((i, v)),
+        )
+        .unwrap();
+
+        assert_eq!(
+            save.as_bytes(),
+            &[0xFF, 0x01, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00]
+        );
+        assert_eq!(invalid, vec![(1, 2)]);
+    }
+
+    #[test]
+    fn remap_bitset_with_log_warns_once_per_unmapped_bit() {
+        let mut src = SaveBinary::new(vec![0u8; 1]);
+        let mut dst = SaveBinary::new(vec![0u8; 1]);
+        src.write_indexed_bit(Address(0), 1, true).unwrap();
+        src.write_indexed_bit(Address(0), 5, true).unwrap();
+
+        let mut log = crate::VecPatchLogSink::new();
+        remap_bitset_with_log(
+            &src,
+            Address(0),
+            8,
+            &mut dst,
+            Address(0),
+            8,
+            |i| (i != 5).then_some(i),
+            &mut log,
+            "test.bits",
+        )
+        .unwrap();
+
+        let entries = log.into_entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].level, crate::PatchLogLevel::Warning);
+        assert_eq!(entries[0].source, "test.bits");
+        assert_eq!(entries[0].message, "bit 5 is set but has no mapping");
+        assert!(dst.read_indexed_bit(Address(0), 1).unwrap());
+        assert!(!dst.read_indexed_bit(Address(0), 5).unwrap());
+    }
+
+    #[test]
+    fn remap_u16_values_processes_zero_entries() {
+        let mut save = SaveBinary::new(vec![0, 0, 1, 0, 2, 0]);
+        let mut invalid: Vec<(usize, u16)> = Vec::new();
+
+        remap_u16_values(
+            &mut save,
+            Address(0),
+            3,
+            |v| (v != 2).then_some(v + 5),
+            |i, v| {
+                invalid.push((i, v));
+                0xFFFF
+            },
+        )
+        .unwrap();
+
+        assert_eq!(save.as_bytes(), &[5, 0, 6, 0, 0xFF, 0xFF]);
+        assert_eq!(invalid, vec![(2, 2)]);
+    }
+
+    #[test]
+    fn remap_with_lookup_uses_table_and_falls_back_to_on_invalid() {
+        let mut table = [None; 256];
+        table[0] = Some(0);
+        table[1] = Some(0x10);
+        table[2] = Some(0x20);
+
+        let mut save = SaveBinary::new(vec![9, 0, 1, 2, 3]);
+        let mut invalid: Vec<(usize, u8)> = Vec::new();
+        remap_fixed_len_u8_with_lookup(&mut save, Address(1), 4, &table, |i, v| {
+            invalid.push((i, v));
+            0xFF
+        })
+        .unwrap();
+
+        assert_eq!(save.as_bytes(), &[9, 0, 0x10, 0x20, 0xFF]);
+        assert_eq!(invalid, vec![(3, 3)]);
+    }
+
+    #[test]
+    fn remap_with_lookup_is_recorded() {
+        let mut table = [None; 256];
+        table[1] = Some(2);
+
+        let mut save = SaveBinary::new(vec![9, 1]).into_recording();
+        remap_fixed_len_u8_with_lookup(&mut save, Address(1), 1, &table, |_, v| v).unwrap();
+
+        assert_eq!(save.writes(), &[(Address(1), 1, 2)]);
+    }
 }
@@ -1,15 +1,124 @@
-use crate::{Address, AddressRange, SaveError, SaveResult, Size};
+use std::ops::{Deref, DerefMut, Range};
+
+use crate::{Address, AddressRange, HexParseError, SaveError, SaveResult, Size};
 
 /// Mutable byte buffer with safe, bounds-checked helpers.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Equality compares only the bytes, not whether writes are being recorded, and cloning never
+/// copies the write log.
+#[derive(Debug)]
 pub struct SaveBinary {
     bytes: Vec<u8>,
+    /// Write log, present only while wrapped in a [`RecordingSaveBinary`].
+    log: Option<WriteLog>,
+}
+
+/// Writes recorded by a [`RecordingSaveBinary`].
+#[derive(Debug, Clone, Default)]
+struct WriteLog {
+    writes: Vec<(Address, u8, u8)>,
+    /// Start index and prior contents of the last range handed out for raw mutable access.
+    ///
+    /// Raw borrows cannot be observed while they are live, so the range is diffed against this
+    /// snapshot before the next write is recorded.
+    raw_snapshot: Option<(usize, Vec<u8>)>,
+}
+
+impl WriteLog {
+    /// Records every byte that changed since the raw-access snapshot was taken.
+    fn flush_raw(&mut self, bytes: &[u8]) {
+        let Some((start, old)) = self.raw_snapshot.take() else {
+            return;
+        };
+        self.writes.extend(
+            old.into_iter()
+                .zip(&bytes[start..])
+                .enumerate()
+                .filter(|(_, (old, new))| old != *new)
+                .map(|(i, (old, &new))| (Address((start + i) as u32), old, new)),
+        );
+    }
 }
 
+impl Clone for SaveBinary {
+    fn clone(&self) -> Self {
+        Self::new(self.bytes.clone())
+    }
+}
+
+impl PartialEq for SaveBinary {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Eq for SaveBinary {}
+
 impl SaveBinary {
     #[must_use]
     pub fn new(bytes: Vec<u8>) -> Self {
-        Self { bytes }
+        Self { bytes, log: None }
+    }
+
+    /// Wraps the buffer so every write made through its helpers is recorded.
+    ///
+    /// See [`RecordingSaveBinary`].
+    #[must_use]
+    pub fn into_recording(mut self) -> RecordingSaveBinary {
+        self.log = Some(WriteLog::default());
+        RecordingSaveBinary { save: self }
+    }
+
+    /// Parses a buffer from hex digits without separators (e.g. `"00a0FF"`).
+    ///
+    /// Upper- and lowercase digits are both accepted.
+    ///
+    /// # Errors
+    /// Returns [`HexParseError`] if the input has an odd length or a non-hex character.
+    ///
+    /// # Example
+    /// ```
+    /// use gb_save_core::SaveBinary;
+    /// let save = SaveBinary::from_hex_str("00A0ff").unwrap();
+    /// assert_eq!(save.as_bytes(), &[0x00, 0xA0, 0xFF]);
+    /// assert_eq!(save.to_hex_string(), "00a0ff");
+    /// ```
+    pub fn from_hex_str(hex: &str) -> Result<Self, HexParseError> {
+        if !hex.len().is_multiple_of(2) {
+            return Err(HexParseError::OddLength { len: hex.len() });
+        }
+
+        let digit = |index: usize, ch: char| {
+            ch.to_digit(16)
+                .map(|d| d as u8)
+                .ok_or(HexParseError::InvalidCharacter { ch, index })
+        };
+
+        let mut bytes = Vec::with_capacity(hex.len() / 2);
+        let mut chars = hex.char_indices();
+        while let Some((hi_index, hi)) = chars.next() {
+            let hi = digit(hi_index, hi)?;
+            let (lo_index, lo) = chars
+                .next()
+                .ok_or(HexParseError::OddLength { len: hex.len() })?;
+            let lo = digit(lo_index, lo)?;
+            bytes.push((hi << 4) | lo);
+        }
+
+        Ok(Self::new(bytes))
+    }
+
+    /// Formats the buffer as lowercase hex digits without separators.
+    #[must_use]
+    pub fn to_hex_string(&self) -> String {
+        use std::fmt::Write as _;
+
+        self.bytes
+            .iter()
+            .fold(String::with_capacity(self.bytes.len() * 2), |mut out, b| {
+                let _ = write!(out, "{b:02x}");
+                out
+            })
     }
 
     #[must_use]
@@ -24,7 +133,7 @@ impl SaveBinary {
 
     #[must_use]
     pub fn as_bytes_mut(&mut self) -> &mut [u8] {
-        &mut self.bytes
+        self.raw_mut(0..self.bytes.len())
     }
 
     #[must_use]
@@ -48,6 +157,29 @@ impl SaveBinary {
         Ok(())
     }
 
+    /// Checks that the bytes at `start` equal `expected` (for example a magic header).
+    ///
+    /// `what` names the field in the error message.
+    ///
+    /// # Errors
+    /// Returns [`SaveError::CorruptedSave`] pointing at the first mismatching byte, or a range
+    /// error if `expected` does not fit in the buffer.
+    pub fn require_bytes(&self, start: Address, expected: &[u8], what: &str) -> SaveResult<()> {
+        let end = self.end_address(start, expected.len() as u32)?;
+        let actual = self.slice(AddressRange::new(start, end))?;
+        let Some(index) = actual.iter().zip(expected).position(|(a, e)| a != e) else {
+            return Ok(());
+        };
+
+        Err(SaveError::CorruptedSave {
+            offset: Address(start.0 + index as u32),
+            reason: format!(
+                "{what} mismatch: expected 0x{:02X}, found 0x{:02X}",
+                expected[index], actual[index]
+            ),
+        })
+    }
+
     fn check_address(&self, address: Address) -> SaveResult<usize> {
         let index = address.as_usize();
         if index >= self.bytes.len() {
@@ -72,6 +204,14 @@ impl SaveBinary {
         Ok(r)
     }
 
+    /// Computes the exclusive end of a `len`-byte span at `start`, failing on `u32` overflow.
+    fn end_address(&self, start: Address, len: u32) -> SaveResult<Address> {
+        start.checked_add(len).ok_or(SaveError::AddressOutOfBounds {
+            address: start,
+            len: self.bytes.len(),
+        })
+    }
+
     pub fn read_u8(&self, address: Address) -> SaveResult<u8> {
         let index = self.check_address(address)?;
         Ok(self.bytes[index])
@@ -79,10 +219,64 @@ impl SaveBinary {
 
     pub fn write_u8(&mut self, address: Address, value: u8) -> SaveResult<()> {
         let index = self.check_address(address)?;
-        self.bytes[index] = value;
+        self.record_writes(index..index + 1, |bytes| bytes[index] = value);
         Ok(())
     }
 
+    /// Copies `snapshot`'s bytes back over this buffer, keeping the write log.
+    ///
+    /// While recording, each byte the restore changes is logged, so a rollback shows up as
+    /// writes undoing the failed ones.
+    pub(crate) fn restore_bytes_from(&mut self, snapshot: &SaveBinary) {
+        if let Some(log) = &mut self.log {
+            log.flush_raw(&self.bytes);
+            log.writes.extend(
+                self.bytes
+                    .iter()
+                    .zip(&snapshot.bytes)
+                    .enumerate()
+                    .filter(|(_, (current, original))| current != original)
+                    .map(|(i, (&current, &original))| (Address(i as u32), current, original)),
+            );
+        }
+        self.bytes.clone_from(&snapshot.bytes);
+    }
+
+    /// Runs `write` on the buffer, logging each byte in `written` if recording is enabled.
+    fn record_writes(&mut self, written: Range<usize>, write: impl FnOnce(&mut [u8])) {
+        let Some(log) = &mut self.log else {
+            write(&mut self.bytes);
+            return;
+        };
+
+        log.flush_raw(&self.bytes);
+        let old = self.bytes[written.clone()].to_vec();
+        write(&mut self.bytes);
+        log.writes.extend(
+            written
+                .zip(old)
+                .map(|(index, old)| (Address(index as u32), old, self.bytes[index])),
+        );
+    }
+
+    /// Adds a signed `delta` to the byte at `address`, wrapping on overflow (`0xFF + 1 == 0`).
+    pub fn wrapping_add_u8(&mut self, address: Address, delta: i8) -> SaveResult<()> {
+        let value = self.read_u8(address)?;
+        self.write_u8(address, value.wrapping_add_signed(delta))
+    }
+
+    /// Adds `delta` to the byte at `address`, clamping at `0xFF`.
+    pub fn saturating_add_u8(&mut self, address: Address, delta: u8) -> SaveResult<()> {
+        let value = self.read_u8(address)?;
+        self.write_u8(address, value.saturating_add(delta))
+    }
+
+    /// Subtracts `delta` from the byte at `address`, clamping at `0`.
+    pub fn saturating_sub_u8(&mut self, address: Address, delta: u8) -> SaveResult<()> {
+        let value = self.read_u8(address)?;
+        self.write_u8(address, value.saturating_sub(delta))
+    }
+
     pub fn read_u16_le(&self, address: Address) -> SaveResult<u16> {
         let lo = self.read_u8(address)?;
         let hi = self.read_u8(Address(address.0 + 1))?;
@@ -109,31 +303,123 @@ impl SaveBinary {
         Ok(())
     }
 
+    /// Copies the bytes in `range` into a new `Vec`.
+    ///
+    /// This allocates; prefer [`SaveBinary::read_bytes_ref`] when a borrowed slice is enough.
     pub fn read_bytes(&self, range: AddressRange) -> SaveResult<Vec<u8>> {
         let r = self.check_range(range)?;
         Ok(self.bytes[r].to_vec())
     }
 
+    /// Borrows the bytes in `range` without copying.
     pub fn slice(&self, range: AddressRange) -> SaveResult<&[u8]> {
         let r = self.check_range(range)?;
         Ok(&self.bytes[r])
     }
 
+    /// Mutably borrows the bytes in `range` without copying.
+    ///
+    /// While recording, bytes changed through the returned slice are logged (in address order)
+    /// once the borrow ends.
     pub fn slice_mut(&mut self, range: AddressRange) -> SaveResult<&mut [u8]> {
         let r = self.check_range(range)?;
-        Ok(&mut self.bytes[r])
+        Ok(self.raw_mut(r))
+    }
+
+    /// Hands out `r` for raw mutable access, snapshotting it first if recording is enabled.
+    fn raw_mut(&mut self, r: Range<usize>) -> &mut [u8] {
+        if let Some(log) = &mut self.log {
+            log.flush_raw(&self.bytes);
+            log.raw_snapshot = Some((r.start, self.bytes[r.clone()].to_vec()));
+        }
+        &mut self.bytes[r]
+    }
+
+    /// Borrowing counterpart of [`SaveBinary::read_bytes`]; an alias for [`SaveBinary::slice`].
+    ///
+    /// Use this in hot paths (checksums, pattern searches) to avoid allocating.
+    pub fn read_bytes_ref(&self, range: AddressRange) -> SaveResult<&[u8]> {
+        self.slice(range)
+    }
+
+    /// Mutable borrowing variant; an alias for [`SaveBinary::slice_mut`].
+    pub fn read_bytes_mut(&mut self, range: AddressRange) -> SaveResult<&mut [u8]> {
+        self.slice_mut(range)
     }
 
     pub fn write_bytes(&mut self, start: Address, data: &[u8]) -> SaveResult<()> {
-        let end = Address(start.0 + data.len() as u32);
+        let len = u32::try_from(data.len()).map_err(|_| SaveError::AddressOutOfBounds {
+            address: start,
+            len: self.bytes.len(),
+        })?;
+        let end = self.end_address(start, len)?;
         let r = self.check_range(AddressRange::new(start, end))?;
-        self.bytes[r].copy_from_slice(data);
+        self.record_writes(r.clone(), |bytes| bytes[r].copy_from_slice(data));
         Ok(())
     }
 
+    /// Slice-style alias for [`SaveBinary::write_bytes`]: copies `src` into the buffer at
+    /// `dst_start`.
+    #[inline]
+    pub fn copy_from_slice(&mut self, dst_start: Address, src: &[u8]) -> SaveResult<()> {
+        self.write_bytes(dst_start, src)
+    }
+
+    /// Alias for [`SaveBinary::read_bytes`], mirroring [`SaveBinary::copy_from_slice`].
+    #[inline]
+    pub fn copy_to_vec(&self, range: AddressRange) -> SaveResult<Vec<u8>> {
+        self.read_bytes(range)
+    }
+
+    /// Reads bytes from `start` up to (not including) the first `0x00`, or at most `max_len`
+    /// bytes.
+    ///
+    /// Reading also stops at the end of the buffer. Returns [`SaveError::AddressOutOfBounds`] if
+    /// `start` is outside the buffer.
+    pub fn read_null_terminated_bytes(
+        &self,
+        start: Address,
+        max_len: usize,
+    ) -> SaveResult<Vec<u8>> {
+        let index = self.check_address(start)?;
+        let end = index.saturating_add(max_len).min(self.bytes.len());
+        let bytes = &self.bytes[index..end];
+        let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        Ok(bytes[..len].to_vec())
+    }
+
+    /// Writes `data` followed by a `0x00` terminator at `start`.
+    ///
+    /// Returns [`SaveError::RangeOutOfBounds`] if `data` plus its terminator does not fit in
+    /// `max_len` bytes or in the buffer.
+    pub fn write_null_terminated_bytes(
+        &mut self,
+        start: Address,
+        data: &[u8],
+        max_len: usize,
+    ) -> SaveResult<()> {
+        if data.len() + 1 > max_len {
+            return Err(SaveError::RangeOutOfBounds {
+                range: AddressRange::new(
+                    start,
+                    Address(start.0.saturating_add((data.len() + 1) as u32)),
+                ),
+                len: self.bytes.len(),
+            });
+        }
+
+        let terminator = self.end_address(start, data.len() as u32)?;
+        self.check_range(AddressRange::new(
+            start,
+            Address(terminator.0.saturating_add(1)),
+        ))?;
+        self.write_bytes(start, data)?;
+        self.write_u8(terminator, 0)
+    }
+
     pub fn fill(&mut self, range: AddressRange, value: u8) -> SaveResult<()> {
         let r = self.check_range(range)?;
-        self.bytes[r].fill(value);
+        self.record_writes(r.clone(), |bytes| bytes[r].fill(value));
         Ok(())
     }
 
@@ -142,7 +428,8 @@ impl SaveBinary {
             return Ok(());
         }
 
-        self.fill(AddressRange::new(start, Address(start.0 + len.0)), value)
+        let end = self.end_address(start, len.0)?;
+        self.fill(AddressRange::new(start, end), value)
     }
 
     pub fn clear_len(&mut self, start: Address, len: Size) -> SaveResult<()> {
@@ -163,12 +450,14 @@ impl SaveBinary {
             return Ok(());
         }
 
-        let src_end = Address(src_start.0 + len.0);
-        let dst_end = Address(dst_start.0 + len.0);
+        let src_end = src.end_address(src_start, len.0)?;
+        let dst_end = self.end_address(dst_start, len.0)?;
 
         let src_range = src.check_range(AddressRange::new(src_start, src_end))?;
         let dst_range = self.check_range(AddressRange::new(dst_start, dst_end))?;
-        self.bytes[dst_range].copy_from_slice(&src.bytes[src_range]);
+        self.record_writes(dst_range.clone(), |bytes| {
+            bytes[dst_range].copy_from_slice(&src.bytes[src_range]);
+        });
         Ok(())
     }
 
@@ -178,13 +467,16 @@ impl SaveBinary {
             return Ok(());
         }
 
-        let src_end = Address(src.0 + len.0);
-        let dst_end = Address(dst.0 + len.0);
+        let src_end = self.end_address(src, len.0)?;
+        let dst_end = self.end_address(dst, len.0)?;
         self.check_range(AddressRange::new(src, src_end))?;
         self.check_range(AddressRange::new(dst, dst_end))?;
 
         let src_range = src.as_usize()..src_end.as_usize();
-        self.bytes.copy_within(src_range, dst.as_usize());
+        let dst_range = dst.as_usize()..dst_end.as_usize();
+        self.record_writes(dst_range, |bytes| {
+            bytes.copy_within(src_range, dst.as_usize());
+        });
         Ok(())
     }
 
@@ -229,6 +521,182 @@ impl SaveBinary {
         let bit = (bit_index % 8) as u8;
         self.write_bit(Address(base.0 + byte_offset), bit, set)
     }
+
+    /// Returns the address of the first byte in `range` for which `pred` returns `true`.
+    pub fn find_first_matching(
+        &self,
+        range: AddressRange,
+        pred: impl Fn(u8) -> bool,
+    ) -> SaveResult<Option<Address>> {
+        let bytes = self.slice(range)?;
+        Ok(bytes
+            .iter()
+            .position(|&b| pred(b))
+            .map(|index| Address(range.start.0 + index as u32)))
+    }
+
+    /// Returns the address of the first non-zero byte in `range`, or `None` if it is all zero.
+    pub fn find_first_nonzero(&self, range: AddressRange) -> SaveResult<Option<Address>> {
+        self.find_first_matching(range, |b| b != 0)
+    }
+
+    /// Returns the address of the first zero byte in `range`, if any.
+    pub fn find_first_zero(&self, range: AddressRange) -> SaveResult<Option<Address>> {
+        self.find_first_matching(range, |b| b == 0)
+    }
+
+    /// Counts how many bytes in `range` equal `value`.
+    pub fn count_occurrences(&self, range: AddressRange, value: u8) -> SaveResult<usize> {
+        Ok(self.slice(range)?.iter().filter(|&&b| b == value).count())
+    }
+
+    /// Returns the largest byte in `range`.
+    ///
+    /// An empty range has no maximum and returns [`SaveError::InvalidAddressRange`].
+    pub fn max_u8_in_range(&self, range: AddressRange) -> SaveResult<u8> {
+        self.slice(range)?
+            .iter()
+            .copied()
+            .max()
+            .ok_or(SaveError::InvalidAddressRange { range })
+    }
+
+    /// Returns the smallest byte in `range`.
+    ///
+    /// An empty range has no minimum and returns [`SaveError::InvalidAddressRange`].
+    pub fn min_u8_in_range(&self, range: AddressRange) -> SaveResult<u8> {
+        self.slice(range)?
+            .iter()
+            .copied()
+            .min()
+            .ok_or(SaveError::InvalidAddressRange { range })
+    }
+
+    /// Returns a 64-bit fingerprint of the bytes in `range`.
+    ///
+    /// The algorithm is 64-bit FNV-1a (offset basis `0xcbf29ce484222325`, prime
+    /// `0x100000001b3`), so values are stable across platforms and Rust versions and can be stored
+    /// in test fixtures. It is not a cryptographic hash.
+    pub fn region_hash(&self, range: AddressRange) -> SaveResult<u64> {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        Ok(self
+            .slice(range)?
+            .iter()
+            .fold(FNV_OFFSET_BASIS, |hash, &b| {
+                (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+            }))
+    }
+
+    /// Returns `(address, self_byte, other_byte)` for every byte that differs from `other`.
+    ///
+    /// Only the overlapping prefix of the two buffers is compared; callers that care about length
+    /// changes should compare [`SaveBinary::len`] separately.
+    #[must_use]
+    pub fn diff(&self, other: &SaveBinary) -> Vec<(Address, u8, u8)> {
+        self.bytes
+            .iter()
+            .zip(&other.bytes)
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(index, (a, b))| (Address(index as u32), *a, *b))
+            .collect()
+    }
+
+    /// Returns the number of bits that differ from `other`, or `None` if the lengths differ.
+    ///
+    /// # Example
+    /// ```
+    /// use gb_save_core::SaveBinary;
+    ///
+    /// let good = SaveBinary::new(vec![0x00, 0x0F]);
+    /// let bad = SaveBinary::new(vec![0x01, 0x0F]);
+    /// assert_eq!(good.hamming_distance(&bad), Some(1));
+    /// ```
+    #[must_use]
+    pub fn hamming_distance(&self, other: &SaveBinary) -> Option<u64> {
+        if self.bytes.len() != other.bytes.len() {
+            return None;
+        }
+        Some(
+            self.bytes
+                .iter()
+                .zip(&other.bytes)
+                .map(|(a, b)| u64::from((a ^ b).count_ones()))
+                .sum(),
+        )
+    }
+}
+
+/// A [`SaveBinary`] that records every write as `(address, old_value, new_value)`.
+///
+/// Derefs to [`SaveBinary`], so it can be passed directly to [`crate::Patch::apply`]. Writes made
+/// through the write helpers (`write_u8`, `write_bytes`, `fill`, `copy_within`, ...) are
+/// recorded in order, including writes that leave a byte unchanged. Raw mutable access through
+/// [`SaveBinary::as_bytes_mut`] or [`SaveBinary::slice_mut`] records only the bytes that changed,
+/// in address order.
+///
+/// # Example
+/// ```
+/// use gb_save_core::{Address, SaveBinary};
+///
+/// let mut save = SaveBinary::new(vec![0; 4]).into_recording();
+/// save.write_u16_le(Address(2), 0x1234).unwrap();
+/// assert_eq!(
+///     save.writes(),
+///     &[(Address(2), 0x00, 0x34), (Address(3), 0x00, 0x12)]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct RecordingSaveBinary {
+    save: SaveBinary,
+}
+
+impl RecordingSaveBinary {
+    /// Returns every recorded write, oldest first.
+    ///
+    /// Takes `&mut self` so changes made through a finished raw borrow can be logged first.
+    #[must_use]
+    pub fn writes(&mut self) -> &[(Address, u8, u8)] {
+        let SaveBinary { bytes, log } = &mut self.save;
+        // The inner buffer can be replaced wholesale through `DerefMut`, dropping its log.
+        let log = log.get_or_insert_with(WriteLog::default);
+        log.flush_raw(bytes);
+        &log.writes
+    }
+
+    /// Stops recording and returns the underlying buffer.
+    #[must_use]
+    pub fn into_inner(mut self) -> SaveBinary {
+        self.save.log = None;
+        self.save
+    }
+}
+
+impl Clone for RecordingSaveBinary {
+    fn clone(&self) -> Self {
+        RecordingSaveBinary {
+            save: SaveBinary {
+                bytes: self.save.bytes.clone(),
+                log: self.save.log.clone(),
+            },
+        }
+    }
+}
+
+impl Deref for RecordingSaveBinary {
+    type Target = SaveBinary;
+
+    fn deref(&self) -> &SaveBinary {
+        &self.save
+    }
+}
+
+impl DerefMut for RecordingSaveBinary {
+    fn deref_mut(&mut self) -> &mut SaveBinary {
+        &mut self.save
+    }
 }
 
 #[cfg(test)]
@@ -251,6 +719,31 @@ mod tests {
         assert_eq!(save.as_bytes(), &[0, 1, 0, 1, 2, 3, 4, 5, 6, 7]);
     }
 
+    #[test]
+    fn diff_reports_changed_bytes_in_common_prefix() {
+        let before = SaveBinary::new(vec![1, 2, 3, 4]);
+        let after = SaveBinary::new(vec![1, 9, 3]);
+        assert_eq!(before.diff(&after), vec![(Address(1), 2, 9)]);
+    }
+
+    #[test]
+    fn hamming_distance_counts_differing_bits() {
+        let zeros = SaveBinary::new(vec![0x00; 4]);
+        assert_eq!(zeros.hamming_distance(&zeros.clone()), Some(0));
+        assert_eq!(
+            zeros.hamming_distance(&SaveBinary::new(vec![0xFF; 4])),
+            Some(32)
+        );
+        assert_eq!(
+            zeros.hamming_distance(&SaveBinary::new(vec![0x80, 0x00, 0x03, 0x00])),
+            Some(3)
+        );
+        assert_eq!(
+            zeros.hamming_distance(&SaveBinary::new(vec![0x00; 5])),
+            None
+        );
+    }
+
     #[test]
     fn out_of_bounds_errors() {
         let save = SaveBinary::new(vec![0; 4]);
@@ -260,4 +753,325 @@ mod tests {
             other => panic!("unexpected error: {other:?}"),
         }
     }
+
+    #[test]
+    fn length_overflow_is_out_of_bounds() {
+        let mut save = SaveBinary::new(vec![0; 4]);
+        let err = save.fill_len(Address(u32::MAX), Size(2), 0).unwrap_err();
+        assert!(matches!(err, SaveError::AddressOutOfBounds { .. }));
+
+        let err = save
+            .copy_within(Address(0), Address(u32::MAX - 1), Size(4))
+            .unwrap_err();
+        assert!(matches!(err, SaveError::AddressOutOfBounds { .. }));
+    }
+
+    #[test]
+    fn read_bytes_ref_borrows_range() {
+        let mut save = SaveBinary::new(vec![1, 2, 3, 4]);
+        let range = AddressRange::new(Address(1), Address(3));
+        assert_eq!(save.read_bytes_ref(range).unwrap(), &[2, 3]);
+        assert_eq!(save.read_bytes(range).unwrap(), vec![2, 3]);
+
+        save.read_bytes_mut(range).unwrap().fill(0);
+        assert_eq!(save.as_bytes(), &[1, 0, 0, 4]);
+
+        let too_long = AddressRange::new(Address(2), Address(5));
+        assert!(save.read_bytes_ref(too_long).is_err());
+    }
+
+    #[test]
+    fn find_first_matching_bytes() {
+        let save = SaveBinary::new(vec![0, 0, 5, 0, 7]);
+        let all = AddressRange::new(Address(0), Address(5));
+        assert_eq!(save.find_first_nonzero(all).unwrap(), Some(Address(2)));
+        assert_eq!(save.find_first_zero(all).unwrap(), Some(Address(0)));
+        assert_eq!(
+            save.find_first_zero(AddressRange::new(Address(2), Address(5)))
+                .unwrap(),
+            Some(Address(3))
+        );
+        assert_eq!(
+            save.find_first_matching(all, |b| b > 5).unwrap(),
+            Some(Address(4))
+        );
+        assert_eq!(
+            save.find_first_nonzero(AddressRange::new(Address(0), Address(2)))
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn find_first_in_empty_range_is_none() {
+        let save = SaveBinary::new(vec![1, 0]);
+        let empty = AddressRange::new(Address(1), Address(1));
+        assert_eq!(save.find_first_nonzero(empty).unwrap(), None);
+        assert_eq!(save.find_first_zero(empty).unwrap(), None);
+        assert_eq!(save.find_first_matching(empty, |_| true).unwrap(), None);
+    }
+
+    #[test]
+    fn find_first_rejects_out_of_bounds_range() {
+        let save = SaveBinary::new(vec![0; 2]);
+        let range = AddressRange::new(Address(0), Address(3));
+        assert!(save.find_first_nonzero(range).is_err());
+    }
+
+    #[test]
+    fn range_statistics() {
+        let save = SaveBinary::new(vec![0xFF, 3, 0xFF, 9, 1]);
+        let all = AddressRange::new(Address(0), Address(5));
+        assert_eq!(save.count_occurrences(all, 0xFF).unwrap(), 2);
+        assert_eq!(save.count_occurrences(all, 0x42).unwrap(), 0);
+
+        let tail = AddressRange::new(Address(1), Address(5));
+        assert_eq!(save.max_u8_in_range(tail).unwrap(), 0xFF);
+        assert_eq!(save.min_u8_in_range(tail).unwrap(), 1);
+
+        let empty = AddressRange::new(Address(2), Address(2));
+        assert_eq!(save.count_occurrences(empty, 0xFF).unwrap(), 0);
+        assert!(matches!(
+            save.max_u8_in_range(empty),
+            Err(SaveError::InvalidAddressRange { .. })
+        ));
+    }
+
+    #[test]
+    fn range_statistics_reject_out_of_bounds() {
+        let save = SaveBinary::new(vec![0; 2]);
+        let range = AddressRange::new(Address(1), Address(3));
+        for result in [
+            save.count_occurrences(range, 0),
+            save.max_u8_in_range(range).map(usize::from),
+            save.min_u8_in_range(range).map(usize::from),
+        ] {
+            assert!(matches!(result, Err(SaveError::RangeOutOfBounds { .. })));
+        }
+    }
+
+    #[test]
+    fn region_hash_is_fnv1a() {
+        let save = SaveBinary::new(b"a".to_vec());
+        let empty = AddressRange::new(Address(0), Address(0));
+        let all = AddressRange::new(Address(0), Address(1));
+        assert_eq!(save.region_hash(empty).unwrap(), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(save.region_hash(all).unwrap(), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn region_hash_detects_single_bit_change() {
+        let mut save = SaveBinary::new(vec![1, 2, 3, 1, 2, 3]);
+        let first = AddressRange::new(Address(0), Address(3));
+        let second = AddressRange::new(Address(3), Address(6));
+        assert_eq!(
+            save.region_hash(first).unwrap(),
+            save.region_hash(second).unwrap()
+        );
+
+        save.write_bit(Address(4), 0, true).unwrap();
+        assert_ne!(
+            save.region_hash(first).unwrap(),
+            save.region_hash(second).unwrap()
+        );
+    }
+
+    #[test]
+    fn read_null_terminated_bytes_stops_at_terminator_or_limit() {
+        let save = SaveBinary::new(vec![b'R', b'E', b'D', 0, b'X', b'Y']);
+        assert_eq!(
+            save.read_null_terminated_bytes(Address(0), 8).unwrap(),
+            b"RED"
+        );
+        assert_eq!(
+            save.read_null_terminated_bytes(Address(0), 2).unwrap(),
+            b"RE"
+        );
+        assert_eq!(
+            save.read_null_terminated_bytes(Address(4), 8).unwrap(),
+            b"XY"
+        );
+        assert!(save
+            .read_null_terminated_bytes(Address(3), 4)
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            save.read_null_terminated_bytes(Address(6), 4),
+            Err(SaveError::AddressOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn write_null_terminated_bytes_appends_terminator() {
+        let mut save = SaveBinary::new(vec![0xFF; 6]);
+        save.write_null_terminated_bytes(Address(1), b"AB", 3)
+            .unwrap();
+        assert_eq!(save.as_bytes(), &[0xFF, b'A', b'B', 0, 0xFF, 0xFF]);
+        assert_eq!(
+            save.read_null_terminated_bytes(Address(1), 3).unwrap(),
+            b"AB"
+        );
+
+        assert!(matches!(
+            save.write_null_terminated_bytes(Address(0), b"ABC", 3),
+            Err(SaveError::RangeOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            save.write_null_terminated_bytes(Address(4), b"AB", 8),
+            Err(SaveError::RangeOutOfBounds { .. })
+        ));
+        assert_eq!(save.as_bytes()[4..], [0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        let save = SaveBinary::new(vec![0x00, 0x42, 0xAB, 0xFF]);
+        let hex = save.to_hex_string();
+        assert_eq!(hex, "0042abff");
+        assert_eq!(SaveBinary::from_hex_str(&hex).unwrap(), save);
+        assert!(SaveBinary::from_hex_str("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn hex_parsing_is_case_insensitive() {
+        assert_eq!(
+            SaveBinary::from_hex_str("abCD").unwrap(),
+            SaveBinary::from_hex_str("ABcd").unwrap()
+        );
+    }
+
+    #[test]
+    fn hex_parsing_errors() {
+        assert_eq!(
+            SaveBinary::from_hex_str("abc"),
+            Err(HexParseError::OddLength { len: 3 })
+        );
+        assert_eq!(
+            SaveBinary::from_hex_str("0g"),
+            Err(HexParseError::InvalidCharacter { ch: 'g', index: 1 })
+        );
+        assert!(SaveBinary::from_hex_str("é0").is_err());
+    }
+
+    #[test]
+    fn byte_arithmetic_helpers() {
+        let mut save = SaveBinary::new(vec![0xFF, 0x01, 0xFE, 0x02]);
+
+        save.wrapping_add_u8(Address(0), 1).unwrap();
+        assert_eq!(save.read_u8(Address(0)).unwrap(), 0x00);
+        save.wrapping_add_u8(Address(1), -2).unwrap();
+        assert_eq!(save.read_u8(Address(1)).unwrap(), 0xFF);
+
+        save.saturating_add_u8(Address(2), 5).unwrap();
+        assert_eq!(save.read_u8(Address(2)).unwrap(), 0xFF);
+        save.saturating_sub_u8(Address(3), 5).unwrap();
+        assert_eq!(save.read_u8(Address(3)).unwrap(), 0x00);
+
+        assert!(save.wrapping_add_u8(Address(4), 1).is_err());
+    }
+
+    #[test]
+    fn slice_style_aliases() {
+        let mut save = SaveBinary::new(vec![0; 4]);
+        save.copy_from_slice(Address(1), &[7, 8]).unwrap();
+        assert_eq!(save.as_bytes(), &[0, 7, 8, 0]);
+        assert_eq!(
+            save.copy_to_vec(AddressRange::new(Address(1), Address(3)))
+                .unwrap(),
+            vec![7, 8]
+        );
+        assert!(save.copy_from_slice(Address(3), &[1, 2]).is_err());
+    }
+
+    #[test]
+    fn require_bytes_reports_first_mismatch_offset() {
+        let save = SaveBinary::new(vec![0, b'G', b'B', b'X', 0]);
+        save.require_bytes(Address(1), b"GB", "magic").unwrap();
+
+        let err = save.require_bytes(Address(1), b"GBS", "magic").unwrap_err();
+        match &err {
+            SaveError::CorruptedSave { offset, reason } => {
+                assert_eq!(*offset, Address(3));
+                assert!(reason.starts_with("magic mismatch"), "{reason}");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert_eq!(
+            err.to_string(),
+            "corrupted save at 0x3: magic mismatch: expected 0x53, found 0x58"
+        );
+
+        assert!(matches!(
+            save.require_bytes(Address(4), b"GB", "magic"),
+            Err(SaveError::RangeOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn recording_logs_each_write() {
+        let mut save = SaveBinary::new(vec![1, 2, 3, 4]).into_recording();
+        save.write_u8(Address(0), 9).unwrap();
+        save.fill(AddressRange::new(Address(1), Address(3)), 0)
+            .unwrap();
+        save.copy_within(Address(0), Address(3), Size(1)).unwrap();
+        save.write_bit(Address(1), 0, true).unwrap();
+
+        assert_eq!(
+            save.writes(),
+            &[
+                (Address(0), 1, 9),
+                (Address(1), 2, 0),
+                (Address(2), 3, 0),
+                (Address(3), 4, 9),
+                (Address(1), 0, 1),
+            ]
+        );
+
+        let save = save.into_inner();
+        assert_eq!(save.as_bytes(), &[9, 1, 0, 9]);
+        assert_eq!(save, SaveBinary::new(vec![9, 1, 0, 9]));
+    }
+
+    #[test]
+    fn recording_logs_changes_made_through_raw_access() {
+        let mut save = SaveBinary::new(vec![1, 2, 3, 4]).into_recording();
+        let bytes = save
+            .slice_mut(AddressRange::new(Address(1), Address(4)))
+            .unwrap();
+        bytes[2] = 7;
+        bytes[0] = 5;
+        save.write_u8(Address(0), 8).unwrap();
+        save.as_bytes_mut()[1] = 6;
+
+        assert_eq!(
+            save.writes(),
+            &[
+                (Address(1), 2, 5),
+                (Address(3), 4, 7),
+                (Address(0), 1, 8),
+                (Address(1), 5, 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn clone_drops_the_write_log() {
+        let mut recording = SaveBinary::new(vec![0; 2]).into_recording();
+        recording.write_u8(Address(0), 1).unwrap();
+
+        let mut copy = recording.clone();
+        assert_eq!(copy.writes(), &[(Address(0), 0, 1)]);
+
+        let mut plain = (*recording).clone();
+        plain.write_u8(Address(1), 1).unwrap();
+        assert!(plain.log.is_none());
+        assert_eq!(recording.writes().len(), 1);
+    }
+
+    #[test]
+    fn recording_skips_failed_writes() {
+        let mut save = SaveBinary::new(vec![0; 2]).into_recording();
+        assert!(save.write_bytes(Address(1), &[1, 2]).is_err());
+        assert!(save.writes().is_empty());
+    }
 }
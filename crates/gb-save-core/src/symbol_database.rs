@@ -1,5 +1,6 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::Read;
+use std::ops::RangeInclusive;
 
 use crate::{Address, SaveError, SaveResult};
 
@@ -50,6 +51,45 @@ impl SymbolDatabase {
         db
     }
 
+    /// Parses a `.sym` text blob, rejecting duplicate symbol names.
+    ///
+    /// Lines that do not match the expected format are ignored, as in
+    /// [`SymbolDatabase::from_sym_text`].
+    ///
+    /// # Errors
+    /// Returns [`SaveError::InvalidSaveState`] on the first duplicate name.
+    pub fn strict_from_sym_text(text: &str) -> SaveResult<Self> {
+        let mut db = Self::new();
+        for line in text.lines() {
+            if let Some((name, symbol)) = parse_sym_line(line) {
+                if db.symbols.contains_key(&name) {
+                    return Err(SaveError::InvalidSaveState {
+                        reason: format!("duplicate symbol: {name}"),
+                    });
+                }
+                db.symbols.insert(name, symbol);
+            }
+        }
+        Ok(db)
+    }
+
+    /// Builds a database from `(name, bank, address)` tuples.
+    ///
+    /// Later entries replace earlier ones with the same name, as in [`SymbolDatabase::from_sym_text`].
+    ///
+    /// # Example
+    /// ```
+    /// use gb_save_core::{Address, SymbolDatabase};
+    ///
+    /// let db = SymbolDatabase::from_entries([("sSaveVersion", 0, 0xABE2)]);
+    /// assert_eq!(db.sram_absolute_address("sSaveVersion").unwrap(), Address(0x0BE2));
+    /// ```
+    pub fn from_entries(iter: impl IntoIterator<Item = (impl Into<String>, u8, u16)>) -> Self {
+        iter.into_iter()
+            .map(|(name, bank, address)| (name.into(), Symbol { bank, address }))
+            .collect()
+    }
+
     /// Parses a gzip-compressed `.sym.gz` payload.
     ///
     /// # Errors
@@ -82,6 +122,40 @@ impl SymbolDatabase {
         self.symbols.contains_key(name)
     }
 
+    /// Looks up a symbol by name, ignoring ASCII case.
+    ///
+    /// An exact match is preferred. Otherwise this scans every symbol (O(n)), so prefer
+    /// [`SymbolDatabase::get_symbol`] with exact names where possible. If several names differ only
+    /// by case, the lexicographically smallest one is used.
+    ///
+    /// # Errors
+    /// Returns an error if no symbol matches.
+    pub fn get_symbol_case_insensitive(&self, name: &str) -> SaveResult<Symbol> {
+        if let Some(symbol) = self.symbols.get(name) {
+            return Ok(*symbol);
+        }
+
+        self.symbols
+            .iter()
+            .filter(|(candidate, _)| candidate.eq_ignore_ascii_case(name))
+            .min_by_key(|(candidate, _)| candidate.as_str())
+            .map(|(_, symbol)| *symbol)
+            .ok_or_else(|| SaveError::SymbolNotFound {
+                name: name.to_string(),
+            })
+    }
+
+    /// Returns true if a symbol exists, ignoring ASCII case (O(n); see
+    /// [`SymbolDatabase::get_symbol_case_insensitive`]).
+    #[must_use]
+    pub fn contains_case_insensitive(&self, name: &str) -> bool {
+        self.contains(name)
+            || self
+                .symbols
+                .keys()
+                .any(|candidate| candidate.eq_ignore_ascii_case(name))
+    }
+
     /// Iterates all symbols.
     ///
     /// The returned iterator yields `(name, symbol)` pairs.
@@ -89,6 +163,38 @@ impl SymbolDatabase {
         self.symbols.iter().map(|(name, sym)| (name.as_str(), *sym))
     }
 
+    /// Iterates all symbols sorted by name.
+    ///
+    /// Unlike [`SymbolDatabase::iter`], the order is deterministic. Names are borrowed; only a
+    /// temporary `Vec` of references is allocated for sorting.
+    pub fn iter_sorted(&self) -> impl Iterator<Item = (&str, Symbol)> {
+        let mut symbols: Vec<_> = self.iter().collect();
+        symbols.sort_unstable_by_key(|(name, _)| *name);
+        symbols.into_iter()
+    }
+
+    /// Copies all symbols into a map sorted by name.
+    #[must_use]
+    pub fn to_btreemap(&self) -> BTreeMap<String, Symbol> {
+        self.symbols
+            .iter()
+            .map(|(name, symbol)| (name.clone(), *symbol))
+            .collect()
+    }
+
+    /// Returns all symbols whose in-bank address falls within `range`, sorted by address.
+    ///
+    /// Ties are ordered by bank, then name. This scans every symbol (O(n)).
+    #[must_use]
+    pub fn symbols_in_address_range(&self, range: RangeInclusive<u16>) -> Vec<(&str, Symbol)> {
+        let mut symbols: Vec<_> = self
+            .iter()
+            .filter(|(_, symbol)| range.contains(&symbol.address))
+            .collect();
+        symbols.sort_unstable_by_key(|(name, symbol)| (symbol.address, symbol.bank, *name));
+        symbols
+    }
+
     /// Resolves a symbol expected to be in SRAM into an absolute save-buffer address.
     ///
     /// # Errors
@@ -102,9 +208,8 @@ impl SymbolDatabase {
             });
         }
 
-        let bank_offset = (symbol.bank as u32) * 0x2000;
-        let address_offset = (symbol.address as u32) - 0xA000;
-        Ok(Address(bank_offset + address_offset))
+        Ok(Address::from_bank_and_offset(symbol.bank, symbol.address)
+            .expect("SRAM addresses are at least 0xA000"))
     }
 
     /// Returns true if `address` is in WRAM.
@@ -170,6 +275,40 @@ impl SymbolDatabase {
         let base = self.sram_absolute_address(base_sram_symbol)?;
         Ok(Address(base.0 + (distance as u32)))
     }
+
+    /// Resolves a WRAM symbol to its byte offset from `wram_base`.
+    ///
+    /// This suits layouts where the save data mirrors WRAM 1:1 starting at `wram_base`.
+    ///
+    /// # Errors
+    /// Returns an error if the symbol is missing, not in WRAM, or before `wram_base`.
+    pub fn wram_absolute_address(&self, name: &str, wram_base: u16) -> SaveResult<Address> {
+        let symbol = self.get_symbol(name)?;
+        if !Self::is_wram_address(symbol.address) {
+            return Err(SaveError::SymbolNotInExpectedRegion {
+                name: name.to_string(),
+                expected: "WRAM",
+                address: symbol.address,
+            });
+        }
+
+        let Some(offset) = symbol.address.checked_sub(wram_base) else {
+            return Err(SaveError::SymbolBeforeBase {
+                symbol: name.to_string(),
+                base: format!("0x{wram_base:04X}"),
+            });
+        };
+
+        Ok(Address(u32::from(offset)))
+    }
+}
+
+impl FromIterator<(String, Symbol)> for SymbolDatabase {
+    fn from_iter<I: IntoIterator<Item = (String, Symbol)>>(iter: I) -> Self {
+        Self {
+            symbols: iter.into_iter().collect(),
+        }
+    }
 }
 
 fn parse_sym_line(line: &str) -> Option<(String, Symbol)> {
@@ -220,6 +359,39 @@ mod tests {
         assert_eq!(db.get_symbol("dup").unwrap().address, 0x0002);
     }
 
+    #[test]
+    fn filters_symbols_by_address_range() {
+        let db = SymbolDatabase::from_entries([
+            ("sEnd", 1, 0xBFFF),
+            ("sStart", 0, 0xA000),
+            ("sSaveVersion", 0, 0xABE2),
+            ("wFoo", 0, 0xC000),
+            ("hBar", 0, 0xFF80),
+            ("Rom", 0, 0x9FFF),
+        ]);
+
+        let names: Vec<&str> = db
+            .symbols_in_address_range(0xA000..=0xBFFF)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["sStart", "sSaveVersion", "sEnd"]);
+        assert!(db.symbols_in_address_range(0xD000..=0xDFFF).is_empty());
+    }
+
+    #[test]
+    fn strict_parse_rejects_duplicates() {
+        let text = "00:0001 dup\n00:0002 dup\n";
+        let err = SymbolDatabase::strict_from_sym_text(text).unwrap_err();
+        assert!(matches!(
+            err,
+            SaveError::InvalidSaveState { ref reason } if reason == "duplicate symbol: dup"
+        ));
+
+        let db = SymbolDatabase::strict_from_sym_text("00:0001 a\ninvalid\n00:0002 b\n").unwrap();
+        assert_eq!(db.get_symbol("b").unwrap().address, 0x0002);
+    }
+
     #[test]
     fn missing_symbol_returns_typed_error() {
         let db = SymbolDatabase::new();
@@ -229,4 +401,126 @@ mod tests {
             other => panic!("unexpected error: {other:?}"),
         }
     }
+
+    fn sorted(db: &SymbolDatabase) -> Vec<(&str, Symbol)> {
+        db.iter_sorted().collect()
+    }
+
+    #[test]
+    fn sorted_views_are_deterministic() {
+        let text = "00:0003 wZeta\n00:0001 sAlpha\n01:0002 hMid\n00:0004 Beta\n";
+        let db = SymbolDatabase::from_sym_text(text);
+
+        let names: Vec<&str> = db.iter_sorted().map(|(name, _)| name).collect();
+        assert_eq!(names, ["Beta", "hMid", "sAlpha", "wZeta"]);
+
+        let map = db.to_btreemap();
+        assert_eq!(map.keys().map(String::as_str).collect::<Vec<_>>(), names);
+        assert_eq!(
+            map["hMid"],
+            Symbol {
+                bank: 1,
+                address: 2
+            }
+        );
+        assert_eq!(SymbolDatabase::from_sym_text(text).to_btreemap(), map);
+    }
+
+    #[test]
+    fn from_entries_matches_sym_text() {
+        let text = "00:ABE2 sSaveVersion\n01:AD0D sChecksum\n00:0001 dup\n00:0002 dup\n";
+        let from_text = SymbolDatabase::from_sym_text(text);
+        let from_entries = SymbolDatabase::from_entries([
+            ("sSaveVersion", 0x00, 0xABE2),
+            ("sChecksum", 0x01, 0xAD0D),
+            ("dup", 0x00, 0x0001),
+            ("dup", 0x00, 0x0002),
+        ]);
+
+        assert_eq!(sorted(&from_entries), sorted(&from_text));
+        assert_eq!(
+            from_entries.sram_absolute_address("sChecksum").unwrap(),
+            from_text.sram_absolute_address("sChecksum").unwrap()
+        );
+    }
+
+    #[test]
+    fn collects_from_name_symbol_pairs() {
+        let db: SymbolDatabase = [(
+            "wPlayerName".to_string(),
+            Symbol {
+                bank: 0,
+                address: 0xD158,
+            },
+        )]
+        .into_iter()
+        .collect();
+        assert_eq!(db.get_symbol("wPlayerName").unwrap().address, 0xD158);
+    }
+
+    #[test]
+    fn wram_absolute_address_is_offset_from_base() {
+        let db = SymbolDatabase::from_entries([
+            ("wPlayerName", 0, 0xD158),
+            ("wBase", 0, 0xD000),
+            ("sSaveVersion", 0, 0xABE2),
+        ]);
+        assert_eq!(
+            db.wram_absolute_address("wPlayerName", 0xD000).unwrap(),
+            Address(0x158)
+        );
+        assert_eq!(
+            db.wram_absolute_address("wBase", 0xD000).unwrap(),
+            Address(0)
+        );
+
+        match db.wram_absolute_address("wBase", 0xD100).unwrap_err() {
+            SaveError::SymbolBeforeBase { symbol, base } => {
+                assert_eq!(symbol, "wBase");
+                assert_eq!(base, "0xD100");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert!(matches!(
+            db.wram_absolute_address("sSaveVersion", 0xC000),
+            Err(SaveError::SymbolNotInExpectedRegion { .. })
+        ));
+    }
+
+    #[test]
+    fn case_insensitive_lookup() {
+        let db = SymbolDatabase::from_entries([("sSaveVersion", 0, 0xABE2), ("wFoo", 0, 0xC000)]);
+
+        assert_eq!(
+            db.get_symbol_case_insensitive("sSaveVersion").unwrap(),
+            db.get_symbol("sSaveVersion").unwrap()
+        );
+        assert_eq!(
+            db.get_symbol_case_insensitive("SSAVEVERSION")
+                .unwrap()
+                .address,
+            0xABE2
+        );
+        assert!(db.contains_case_insensitive("ssaveversion"));
+        assert!(!db.contains("ssaveversion"));
+
+        assert!(!db.contains_case_insensitive("sMissing"));
+        assert!(matches!(
+            db.get_symbol_case_insensitive("sMissing"),
+            Err(SaveError::SymbolNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn case_insensitive_lookup_prefers_exact_match() {
+        let db = SymbolDatabase::from_entries([("wfoo", 0, 0xC001), ("wFoo", 0, 0xC002)]);
+        assert_eq!(
+            db.get_symbol_case_insensitive("wFoo").unwrap().address,
+            0xC002
+        );
+        assert_eq!(
+            db.get_symbol_case_insensitive("WFOO").unwrap().address,
+            0xC002
+        );
+    }
 }
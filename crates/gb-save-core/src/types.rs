@@ -18,6 +18,22 @@ pub fn bits_to_bytes(bits: usize) -> usize {
     bits.div_ceil(8)
 }
 
+/// Returns the number of bits stored in `bytes` bytes.
+///
+/// This is the inverse of [`bits_to_bytes`] for whole bytes.
+///
+/// # Example
+/// ```
+/// use gb_save_core::{bits_to_bytes, bytes_to_bits};
+/// assert_eq!(bytes_to_bits(0), 0);
+/// assert_eq!(bytes_to_bits(2), 16);
+/// assert_eq!(bits_to_bytes(bytes_to_bits(5)), 5);
+/// ```
+#[must_use]
+pub fn bytes_to_bits(bytes: usize) -> usize {
+    bytes * 8
+}
+
 /// Absolute address into a save buffer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Address(
@@ -26,11 +42,76 @@ pub struct Address(
 );
 
 impl Address {
+    /// Converts a banked SRAM address (`0xA000..0xC000`) into an absolute save-buffer address.
+    ///
+    /// Each SRAM bank is `0x2000` bytes, so this computes `bank * 0x2000 + (offset - 0xA000)`.
+    /// Returns `None` if `offset` is below `0xA000`.
+    ///
+    /// # Example
+    /// ```
+    /// use gb_save_core::Address;
+    /// assert_eq!(Address::from_bank_and_offset(0, 0xA000), Some(Address(0)));
+    /// assert_eq!(Address::from_bank_and_offset(1, 0xA010), Some(Address(0x2010)));
+    /// assert_eq!(Address::from_bank_and_offset(0, 0x8000), None);
+    /// ```
+    #[must_use]
+    pub fn from_bank_and_offset(bank: u8, offset: u16) -> Option<Address> {
+        let offset = offset.checked_sub(0xA000)?;
+        Some(Address(u32::from(bank) * 0x2000 + u32::from(offset)))
+    }
+
     /// Converts the address to a `usize` index for use with slices.
     #[must_use]
     pub fn as_usize(self) -> usize {
         self.0 as usize
     }
+
+    /// Returns `self + offset`, or `None` if the result would overflow `u32`.
+    ///
+    /// # Example
+    /// ```
+    /// use gb_save_core::Address;
+    /// assert_eq!(Address(0x10).checked_add(2), Some(Address(0x12)));
+    /// assert_eq!(Address(u32::MAX).checked_add(1), None);
+    /// ```
+    #[must_use]
+    pub fn checked_add(self, offset: u32) -> Option<Address> {
+        self.0.checked_add(offset).map(Address)
+    }
+
+    /// Returns `self - offset`, or `None` if the result would underflow.
+    #[must_use]
+    pub fn checked_sub(self, offset: u32) -> Option<Address> {
+        self.0.checked_sub(offset).map(Address)
+    }
+
+    /// Returns `self + offset`, clamped to `u32::MAX`.
+    #[must_use]
+    pub fn saturating_add(self, offset: u32) -> Address {
+        Address(self.0.saturating_add(offset))
+    }
+
+    /// Returns `self - offset`, clamped to `0`.
+    #[must_use]
+    pub fn saturating_sub(self, offset: u32) -> Address {
+        Address(self.0.saturating_sub(offset))
+    }
+
+    /// Returns `true` if the address is a multiple of `alignment`.
+    ///
+    /// An alignment of zero only matches `Address(0)`.
+    #[must_use]
+    pub fn is_aligned(self, alignment: u32) -> bool {
+        self.0.is_multiple_of(alignment)
+    }
+
+    /// Rounds up to the next multiple of `alignment`.
+    ///
+    /// Returns `None` on overflow or if `alignment` is zero.
+    #[must_use]
+    pub fn align_up(self, alignment: u32) -> Option<Address> {
+        self.0.checked_next_multiple_of(alignment).map(Address)
+    }
 }
 
 impl fmt::Display for Address {
@@ -52,6 +133,30 @@ impl Size {
     pub fn as_usize(self) -> usize {
         self.0 as usize
     }
+
+    /// Returns the length of `range` (zero for an inverted range); see [`AddressRange::len`].
+    #[must_use]
+    pub fn from_range(range: AddressRange) -> Size {
+        range.len()
+    }
+
+    /// Returns `self * factor`, or `None` on overflow.
+    #[must_use]
+    pub fn checked_mul(self, factor: u32) -> Option<Size> {
+        self.0.checked_mul(factor).map(Size)
+    }
+
+    /// Returns `self * factor`, clamped to `u32::MAX`.
+    #[must_use]
+    pub fn saturating_mul(self, factor: u32) -> Size {
+        Size(self.0.saturating_mul(factor))
+    }
+
+    /// Returns `self + other`, or `None` on overflow.
+    #[must_use]
+    pub fn checked_add(self, other: Size) -> Option<Size> {
+        self.0.checked_add(other.0).map(Size)
+    }
 }
 
 impl fmt::Display for Size {
@@ -76,17 +181,134 @@ impl AddressRange {
         Self { start, end }
     }
 
+    /// Creates the range `[start, start + len)`.
+    ///
+    /// A zero `len` produces an empty range with `start == end`. Use [`Address::checked_add`]
+    /// first if `start + len` may exceed `u32::MAX`; overflow panics in debug builds.
+    ///
+    /// # Example
+    /// ```
+    /// use gb_save_core::{Address, AddressRange, Size};
+    /// let r = AddressRange::from_start_and_len(Address(0x10), Size(4));
+    /// assert_eq!(r, AddressRange::new(Address(0x10), Address(0x14)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_start_and_len(start: Address, len: Size) -> Self {
+        Self::new(start, Address(start.0 + len.0))
+    }
+
+    /// Creates the range covering `count` bytes from `start`.
+    ///
+    /// Equivalent to [`AddressRange::from_start_and_len`] with `Size(count)`.
+    #[inline]
+    #[must_use]
+    pub fn from_start_and_count(start: Address, count: u32) -> Self {
+        Self::from_start_and_len(start, Size(count))
+    }
+
     /// Returns the range length in bytes.
     #[must_use]
     pub fn len(self) -> Size {
         Size(self.end.0.saturating_sub(self.start.0))
     }
 
+    /// Returns `true` if the range contains no bytes (`start >= end`).
+    #[must_use]
+    pub fn is_empty(self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Iterates over every address in the range, in ascending order.
+    pub fn iter_addresses(self) -> impl Iterator<Item = Address> {
+        (self.start.0..self.end.0).map(Address)
+    }
+
     /// Converts the range to a `Range<usize>` suitable for slice indexing.
     #[must_use]
     pub fn to_usize_range(self) -> Range<usize> {
         self.start.as_usize()..self.end.as_usize()
     }
+
+    /// Splits the range into consecutive sub-ranges of `chunk_size` bytes.
+    ///
+    /// The last chunk is shorter if the range length is not a multiple of `chunk_size`.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is zero (like [`slice::chunks`]).
+    ///
+    /// # Example
+    /// ```
+    /// use gb_save_core::{Address, AddressRange, Size};
+    /// let r = AddressRange::new(Address(0), Address(0x4000));
+    /// assert_eq!(r.chunk(Size(0x2000)).count(), 2);
+    /// ```
+    pub fn chunk(self, chunk_size: Size) -> impl Iterator<Item = AddressRange> {
+        assert!(chunk_size.0 != 0, "chunk_size must be non-zero");
+        let end = self.end;
+        (self.start.0..self.end.0)
+            .step_by(chunk_size.as_usize())
+            .map(move |start| {
+                let chunk_end = start.saturating_add(chunk_size.0).min(end.0);
+                AddressRange::new(Address(start), Address(chunk_end))
+            })
+    }
+
+    /// Returns `true` if `addr` lies within `[start, end)`.
+    #[must_use]
+    pub fn contains(self, addr: Address) -> bool {
+        self.start <= addr && addr < self.end
+    }
+
+    /// Returns `true` if the two ranges share at least one byte.
+    ///
+    /// Adjacent ranges such as `[0, 4)` and `[4, 8)` do not overlap.
+    #[must_use]
+    pub fn overlaps(self, other: AddressRange) -> bool {
+        self.start.max(other.start) < self.end.min(other.end)
+    }
+
+    /// Returns the bytes common to both ranges, or `None` if they do not overlap.
+    #[must_use]
+    pub fn intersection(self, other: AddressRange) -> Option<AddressRange> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        (start < end).then_some(AddressRange::new(start, end))
+    }
+
+    /// Returns the smallest range containing both ranges (including any gap between them).
+    #[must_use]
+    pub fn union(self, other: AddressRange) -> AddressRange {
+        AddressRange::new(self.start.min(other.start), self.end.max(other.end))
+    }
+
+    /// Coalesces ranges that overlap or touch (one ends where the next starts).
+    ///
+    /// Returns sorted, non-overlapping, non-adjacent ranges. Empty ranges are dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use gb_save_core::{Address, AddressRange};
+    /// let r = |s, e| AddressRange::new(Address(s), Address(e));
+    /// assert_eq!(
+    ///     AddressRange::merge_adjacent(&[r(4, 8), r(0, 4), r(10, 12)]),
+    ///     vec![r(0, 8), r(10, 12)]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn merge_adjacent(ranges: &[AddressRange]) -> Vec<AddressRange> {
+        let mut sorted: Vec<_> = ranges.iter().copied().filter(|r| !r.is_empty()).collect();
+        sorted.sort_unstable_by_key(|r| r.start);
+
+        let mut merged: Vec<AddressRange> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        merged
+    }
 }
 
 impl fmt::Display for AddressRange {
@@ -94,3 +316,208 @@ impl fmt::Display for AddressRange {
         write!(f, "[{}, {})", self.start, self.end)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_arithmetic_detects_overflow() {
+        assert_eq!(Address(1).checked_add(2), Some(Address(3)));
+        assert_eq!(Address(u32::MAX).checked_add(0), Some(Address(u32::MAX)));
+        assert_eq!(Address(u32::MAX).checked_add(1), None);
+        assert_eq!(Address(u32::MAX - 1).checked_add(2), None);
+
+        assert_eq!(Address(3).checked_sub(3), Some(Address(0)));
+        assert_eq!(Address(0).checked_sub(1), None);
+    }
+
+    #[test]
+    fn from_bank_and_offset_rejects_non_sram_offsets() {
+        assert_eq!(Address::from_bank_and_offset(0, 0xA000), Some(Address(0)));
+        assert_eq!(
+            Address::from_bank_and_offset(3, 0xBFFF),
+            Some(Address(3 * 0x2000 + 0x1FFF))
+        );
+        assert_eq!(Address::from_bank_and_offset(1, 0x9FFF), None);
+        assert_eq!(Address::from_bank_and_offset(0, 0), None);
+    }
+
+    #[test]
+    fn saturating_arithmetic_clamps() {
+        assert_eq!(Address(u32::MAX).saturating_add(1), Address(u32::MAX));
+        assert_eq!(Address(u32::MAX - 1).saturating_add(5), Address(u32::MAX));
+        assert_eq!(Address(1).saturating_sub(5), Address(0));
+        assert_eq!(Address(5).saturating_sub(1), Address(4));
+    }
+
+    #[test]
+    fn size_arithmetic_detects_overflow() {
+        assert_eq!(Size::from_range(range(4, 10)), Size(6));
+        assert_eq!(Size::from_range(range(10, 4)), Size(0));
+
+        assert_eq!(Size(3).checked_mul(4), Some(Size(12)));
+        assert_eq!(Size(u32::MAX / 2 + 1).checked_mul(2), None);
+        assert_eq!(Size(u32::MAX / 2 + 1).saturating_mul(2), Size(u32::MAX));
+        assert_eq!(Size(3).saturating_mul(4), Size(12));
+
+        assert_eq!(Size(3).checked_add(Size(4)), Some(Size(7)));
+        assert_eq!(Size(u32::MAX).checked_add(Size(1)), None);
+    }
+
+    #[test]
+    fn alignment_helpers() {
+        assert!(Address(4).is_aligned(4));
+        assert!(Address(0).is_aligned(2));
+        assert!(!Address(3).is_aligned(2));
+        assert!(Address(6).is_aligned(3));
+
+        assert_eq!(Address(3).align_up(4), Some(Address(4)));
+        assert_eq!(Address(4).align_up(4), Some(Address(4)));
+        assert_eq!(Address(0).align_up(4), Some(Address(0)));
+        assert_eq!(Address(u32::MAX).align_up(1), Some(Address(u32::MAX)));
+        assert_eq!(Address(u32::MAX).align_up(2), None);
+        assert_eq!(Address(3).align_up(0), None);
+        assert!(!Address(3).is_aligned(0));
+    }
+
+    fn range(start: u32, end: u32) -> AddressRange {
+        AddressRange::new(Address(start), Address(end))
+    }
+
+    #[test]
+    fn merge_adjacent_coalesces_touching_and_overlapping_ranges() {
+        // Disjoint ranges are only sorted.
+        assert_eq!(
+            AddressRange::merge_adjacent(&[range(10, 12), range(0, 2), range(5, 6)]),
+            vec![range(0, 2), range(5, 6), range(10, 12)]
+        );
+        // Adjacent ranges merge.
+        assert_eq!(
+            AddressRange::merge_adjacent(&[range(2, 4), range(0, 2), range(4, 5)]),
+            vec![range(0, 5)]
+        );
+        // Overlapping and contained ranges merge.
+        assert_eq!(
+            AddressRange::merge_adjacent(&[range(0, 5), range(3, 8), range(4, 6)]),
+            vec![range(0, 8)]
+        );
+        // Mixed, with an empty range dropped.
+        assert_eq!(
+            AddressRange::merge_adjacent(&[
+                range(20, 30),
+                range(7, 7),
+                range(0, 4),
+                range(25, 32),
+                range(4, 6),
+                range(40, 41),
+            ]),
+            vec![range(0, 6), range(20, 32), range(40, 41)]
+        );
+        assert!(AddressRange::merge_adjacent(&[]).is_empty());
+    }
+
+    #[test]
+    fn contains_is_half_open() {
+        let r = range(2, 4);
+        assert!(!r.contains(Address(1)));
+        assert!(r.contains(Address(2)));
+        assert!(r.contains(Address(3)));
+        assert!(!r.contains(Address(4)));
+    }
+
+    #[test]
+    fn adjacent_ranges_do_not_overlap() {
+        let a = range(0, 4);
+        let b = range(4, 8);
+        assert!(!a.overlaps(b));
+        assert!(!b.overlaps(a));
+        assert_eq!(a.intersection(b), None);
+        assert_eq!(a.union(b), range(0, 8));
+    }
+
+    #[test]
+    fn fully_contained_range() {
+        let outer = range(0, 10);
+        let inner = range(3, 5);
+        assert!(outer.overlaps(inner));
+        assert!(inner.overlaps(outer));
+        assert_eq!(outer.intersection(inner), Some(inner));
+        assert_eq!(outer.union(inner), outer);
+    }
+
+    #[test]
+    fn partially_overlapping_ranges() {
+        let a = range(0, 6);
+        let b = range(4, 10);
+        assert!(a.overlaps(b));
+        assert_eq!(a.intersection(b), Some(range(4, 6)));
+        assert_eq!(b.intersection(a), Some(range(4, 6)));
+        assert_eq!(a.union(b), range(0, 10));
+    }
+
+    #[test]
+    fn union_spans_gap_between_disjoint_ranges() {
+        assert_eq!(range(0, 2).union(range(6, 8)), range(0, 8));
+        assert!(!range(0, 2).overlaps(range(6, 8)));
+    }
+
+    #[test]
+    fn from_start_and_len_zero_is_empty() {
+        let r = AddressRange::from_start_and_len(Address(7), Size(0));
+        assert_eq!(r.start, r.end);
+        assert_eq!(r.len(), Size(0));
+        assert_eq!(AddressRange::from_start_and_count(Address(7), 0), r);
+    }
+
+    #[test]
+    fn from_start_and_len_max_size() {
+        let r = AddressRange::from_start_and_len(Address(0), Size(u32::MAX));
+        assert_eq!(r, range(0, u32::MAX));
+        assert_eq!(r.len(), Size(u32::MAX));
+
+        let r = AddressRange::from_start_and_count(Address(u32::MAX), 0);
+        assert_eq!(r, range(u32::MAX, u32::MAX));
+    }
+
+    #[test]
+    fn chunk_yields_short_last_chunk() {
+        let sizes: Vec<u32> = range(0, 10).chunk(Size(3)).map(|c| c.len().0).collect();
+        assert_eq!(sizes, vec![3, 3, 3, 1]);
+
+        let chunks: Vec<AddressRange> = range(5, 15).chunk(Size(5)).collect();
+        assert_eq!(chunks, vec![range(5, 10), range(10, 15)]);
+    }
+
+    #[test]
+    fn chunk_of_empty_range_is_empty() {
+        assert_eq!(range(4, 4).chunk(Size(3)).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk_size must be non-zero")]
+    fn chunk_rejects_zero_size() {
+        let _ = range(0, 10).chunk(Size(0));
+    }
+
+    #[test]
+    fn iter_addresses_covers_range() {
+        assert_eq!(
+            range(7, 8).iter_addresses().collect::<Vec<_>>(),
+            vec![Address(7)]
+        );
+        assert_eq!(range(7, 7).iter_addresses().count(), 0);
+        assert_eq!(range(8, 7).iter_addresses().count(), 0);
+        assert_eq!(
+            range(1, 4).iter_addresses().collect::<Vec<_>>(),
+            vec![Address(1), Address(2), Address(3)]
+        );
+    }
+
+    #[test]
+    fn is_empty_when_start_not_before_end() {
+        assert!(range(3, 3).is_empty());
+        assert!(range(4, 3).is_empty());
+        assert!(!range(3, 4).is_empty());
+    }
+}
@@ -1,8 +1,87 @@
-use gb_save_core::{PatchLogEntry, PatchLogLevel};
+use gb_save_core::SaveError;
+#[cfg(target_arch = "wasm32")]
+use gb_save_core::{PatchLogEntry, PatchLogLevel, PatchMetadata, PatchPlan, SaveResult};
+#[cfg(target_arch = "wasm32")]
 use js_sys::{Array, Object, Reflect, Uint8Array};
+#[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
+const TS_TYPES: &str = include_str!("../types/index.d.ts");
+
+/// Returns TypeScript declarations (`index.d.ts`) for the JS shapes built by this module.
+///
+/// `wasm-bindgen` only types the exported functions themselves; these declarations cover the
+/// objects they return (for example `GbSavePatchOutcome`). This is available on every target so
+/// build scripts can write it next to the generated bindings.
+///
+/// # Example
+/// ```
+/// let dts = gb_save_web::js::generate_ts_types();
+/// assert!(dts.contains("export interface GbSavePatchOutcome"));
+/// ```
+#[must_use]
+pub fn generate_ts_types() -> String {
+    TS_TYPES.to_string()
+}
+
+/// Returns a stable `SCREAMING_SNAKE_CASE` code identifying the kind of `SaveError`.
+///
+/// This is exposed to JavaScript as `error_code` so frontends can branch on failures without
+/// parsing the human-readable `error` message.
+///
+/// # Example
+/// ```
+/// use gb_save_core::SaveError;
+///
+/// let err = SaveError::UnknownFixPatch { dev_type: 9 };
+/// assert_eq!(gb_save_web::js::error_to_code(&err), "UNKNOWN_FIX_PATCH");
+/// ```
+#[must_use]
+pub fn error_to_code(e: &SaveError) -> &'static str {
+    match e {
+        SaveError::SaveTooSmall { .. } => "SAVE_TOO_SMALL",
+        SaveError::AddressOutOfBounds { .. } => "ADDRESS_OUT_OF_BOUNDS",
+        SaveError::RangeOutOfBounds { .. } => "RANGE_OUT_OF_BOUNDS",
+        SaveError::InvalidBitIndex { .. } => "INVALID_BIT_INDEX",
+        SaveError::InvalidAddressRange { .. } => "INVALID_ADDRESS_RANGE",
+        SaveError::SizeMismatch { .. } => "SIZE_MISMATCH",
+        SaveError::SymbolNotFound { .. } => "SYMBOL_NOT_FOUND",
+        SaveError::SymbolFileDecompressionFailed => "SYMBOL_FILE_DECOMPRESSION_FAILED",
+        SaveError::SymbolNotInSram { .. } => "SYMBOL_NOT_IN_SRAM",
+        SaveError::SymbolNotInExpectedRegion { .. } => "SYMBOL_NOT_IN_EXPECTED_REGION",
+        SaveError::SymbolBeforeBase { .. } => "SYMBOL_BEFORE_BASE",
+        SaveError::UnsupportedMigrationDirection { .. } => "UNSUPPORTED_MIGRATION_DIRECTION",
+        SaveError::VersionNotSupported { .. } => "VERSION_NOT_SUPPORTED",
+        SaveError::MissingMigrationStep { .. } => "MISSING_MIGRATION_STEP",
+        SaveError::PatchFailed { .. } => "PATCH_FAILED",
+        SaveError::UnknownFixPatch { .. } => "UNKNOWN_FIX_PATCH",
+        SaveError::UnknownPatchId { .. } => "UNKNOWN_PATCH_ID",
+        SaveError::DuplicatePatchId { .. } => "DUPLICATE_PATCH_ID",
+        SaveError::NotImplemented { .. } => "NOT_IMPLEMENTED",
+        SaveError::ChecksumMismatch { .. } => "CHECKSUM_MISMATCH",
+        SaveError::ChecksumMismatch32 { .. } => "CHECKSUM_MISMATCH_32",
+        SaveError::CorruptedSave { .. } => "CORRUPTED_SAVE",
+        SaveError::InvalidSaveState { .. } => "INVALID_SAVE_STATE",
+        SaveError::MultipleErrors(_) => "MULTIPLE_ERRORS",
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn set_error(obj: &Object, e: &SaveError) {
+    let _ = Reflect::set(
+        obj,
+        &JsValue::from_str("error"),
+        &JsValue::from_str(&e.to_string()),
+    );
+    let _ = Reflect::set(
+        obj,
+        &JsValue::from_str("error_code"),
+        &JsValue::from_str(error_to_code(e)),
+    );
+}
+
 /// Converts structured patch logs to a JS-friendly array.
+#[cfg(target_arch = "wasm32")]
 #[must_use]
 pub fn logs_to_js(logs: &[PatchLogEntry]) -> Array {
     let js_logs = Array::new();
@@ -31,7 +110,7 @@ pub fn logs_to_js(logs: &[PatchLogEntry]) -> Array {
         let _ = Reflect::set(
             &e,
             &JsValue::from_str("source"),
-            &JsValue::from_str(entry.source),
+            &JsValue::from_str(&entry.source),
         );
         let _ = Reflect::set(
             &e,
@@ -50,13 +129,15 @@ pub fn logs_to_js(logs: &[PatchLogEntry]) -> Array {
 /// The returned object has the shape:
 /// - `ok: boolean`
 /// - `error?: string`
+/// - `error_code?: string` (see [`error_to_code`])
 /// - `bytes?: Uint8Array`
 /// - `logs: Array<{ level: "info" | "warn" | "error", className: string, source: string, message: string }>`
+#[cfg(target_arch = "wasm32")]
 #[must_use]
 pub fn patch_outcome_to_js(
     bytes: Option<&[u8]>,
     logs: &[PatchLogEntry],
-    error: Option<&str>,
+    error: Option<&SaveError>,
 ) -> JsValue {
     let obj = Object::new();
 
@@ -64,7 +145,7 @@ pub fn patch_outcome_to_js(
     let _ = Reflect::set(&obj, &JsValue::from_str("ok"), &JsValue::from_bool(ok));
 
     if let Some(error) = error {
-        let _ = Reflect::set(&obj, &JsValue::from_str("error"), &JsValue::from_str(error));
+        set_error(&obj, error);
     }
 
     if let Some(out_bytes) = bytes {
@@ -76,3 +157,278 @@ pub fn patch_outcome_to_js(
 
     obj.into()
 }
+
+/// Builds a patch outcome object that reports changed bytes instead of the full output.
+///
+/// When `bytes` has the same length as `input`, the result matches [`patch_outcome_to_js`] but
+/// carries `diff: Array<{ address: number, before: number, after: number }>` in place of `bytes`.
+/// If the length changed (or there are no output bytes), a diff cannot describe the result, so this
+/// falls back to [`patch_outcome_to_js`] and `diff` is omitted.
+#[cfg(target_arch = "wasm32")]
+#[must_use]
+pub fn patch_outcome_with_diff_to_js(
+    input: &[u8],
+    bytes: Option<&[u8]>,
+    logs: &[PatchLogEntry],
+    error: Option<&SaveError>,
+) -> JsValue {
+    let Some(out_bytes) = bytes.filter(|out| out.len() == input.len()) else {
+        return patch_outcome_to_js(bytes, logs, error);
+    };
+
+    let diff = Array::new();
+    for (address, (&before, &after)) in input.iter().zip(out_bytes).enumerate() {
+        if before == after {
+            continue;
+        }
+        let change = Object::new();
+        let _ = Reflect::set(
+            &change,
+            &JsValue::from_str("address"),
+            &JsValue::from(address as u32),
+        );
+        let _ = Reflect::set(
+            &change,
+            &JsValue::from_str("before"),
+            &JsValue::from(before),
+        );
+        let _ = Reflect::set(&change, &JsValue::from_str("after"), &JsValue::from(after));
+        diff.push(&change);
+    }
+
+    let obj = patch_outcome_to_js(None, logs, error);
+    let _ = Reflect::set(&obj, &JsValue::from_str("diff"), &diff);
+    obj
+}
+
+/// Builds a JavaScript object describing the patches a build supports.
+///
+/// `fixes` pairs each fix patch's `dev_type` with its metadata. The returned object has the shape:
+/// - `migrations: Array<{ id: string, from_version: number, to_version: number, description: string }>`
+/// - `fixes: Array<{ id: string, dev_type: number, description: string }>`
+/// - `min_version: number`
+/// - `max_version: number`
+///
+/// Migrations without both `from_version` and `to_version` are skipped.
+#[cfg(target_arch = "wasm32")]
+#[must_use]
+pub fn patch_metadata_to_js(
+    migrations: &[PatchMetadata],
+    fixes: &[(u8, PatchMetadata)],
+    min_version: u16,
+    max_version: u16,
+) -> JsValue {
+    let js_migrations = Array::new();
+    for meta in migrations {
+        let (Some(from), Some(to)) = (meta.from_version, meta.to_version) else {
+            continue;
+        };
+
+        let m = Object::new();
+        let _ = Reflect::set(&m, &JsValue::from_str("id"), &JsValue::from_str(meta.id));
+        let _ = Reflect::set(&m, &JsValue::from_str("from_version"), &JsValue::from(from));
+        let _ = Reflect::set(&m, &JsValue::from_str("to_version"), &JsValue::from(to));
+        let _ = Reflect::set(
+            &m,
+            &JsValue::from_str("description"),
+            &JsValue::from_str(meta.description),
+        );
+        js_migrations.push(&m);
+    }
+
+    let js_fixes = Array::new();
+    for (dev_type, meta) in fixes {
+        let f = Object::new();
+        let _ = Reflect::set(&f, &JsValue::from_str("id"), &JsValue::from_str(meta.id));
+        let _ = Reflect::set(
+            &f,
+            &JsValue::from_str("dev_type"),
+            &JsValue::from(*dev_type),
+        );
+        let _ = Reflect::set(
+            &f,
+            &JsValue::from_str("description"),
+            &JsValue::from_str(meta.description),
+        );
+        js_fixes.push(&f);
+    }
+
+    let obj = Object::new();
+    let _ = Reflect::set(&obj, &JsValue::from_str("migrations"), &js_migrations);
+    let _ = Reflect::set(&obj, &JsValue::from_str("fixes"), &js_fixes);
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("min_version"),
+        &JsValue::from(min_version),
+    );
+    let _ = Reflect::set(
+        &obj,
+        &JsValue::from_str("max_version"),
+        &JsValue::from(max_version),
+    );
+
+    obj.into()
+}
+
+/// Builds a JavaScript object previewing a resolved migration plan.
+///
+/// The returned object has the shape:
+/// - `ok: boolean`
+/// - `steps: Array<{ id: string, from: number, to: number }>` (empty on error)
+/// - `error?: string`
+/// - `error_code?: string` (see [`error_to_code`])
+///
+/// Steps of a downgrade plan are reported in the direction they run (newer to older).
+#[cfg(target_arch = "wasm32")]
+#[must_use]
+pub fn migration_plan_to_js(plan: &SaveResult<PatchPlan>) -> JsValue {
+    let obj = Object::new();
+    let steps = Array::new();
+
+    match plan {
+        Ok(plan) => {
+            for patch in plan {
+                let meta = patch.metadata();
+                let (mut from, mut to) = (meta.from_version, meta.to_version);
+                if plan.is_downgrade() {
+                    std::mem::swap(&mut from, &mut to);
+                }
+
+                let step = Object::new();
+                let _ = Reflect::set(&step, &JsValue::from_str("id"), &JsValue::from_str(meta.id));
+                let _ = Reflect::set(&step, &JsValue::from_str("from"), &JsValue::from(from));
+                let _ = Reflect::set(&step, &JsValue::from_str("to"), &JsValue::from(to));
+                steps.push(&step);
+            }
+            let _ = Reflect::set(&obj, &JsValue::from_str("ok"), &JsValue::TRUE);
+        }
+        Err(e) => {
+            let _ = Reflect::set(&obj, &JsValue::from_str("ok"), &JsValue::FALSE);
+            set_error(&obj, e);
+        }
+    }
+
+    let _ = Reflect::set(&obj, &JsValue::from_str("steps"), &steps);
+    obj.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gb_save_core::{Address, AddressRange, Size};
+    use std::collections::HashSet;
+
+    #[test]
+    fn error_codes_are_distinct_and_non_empty() {
+        let errors = [
+            SaveError::SaveTooSmall { min: 2, actual: 1 },
+            SaveError::AddressOutOfBounds {
+                address: Address(4),
+                len: 2,
+            },
+            SaveError::RangeOutOfBounds {
+                range: AddressRange::new(Address(0), Address(4)),
+                len: 2,
+            },
+            SaveError::InvalidBitIndex { bit: 8 },
+            SaveError::InvalidAddressRange {
+                range: AddressRange::new(Address(4), Address(0)),
+            },
+            SaveError::SizeMismatch {
+                expected: Size(2),
+                actual: Size(1),
+            },
+            SaveError::SymbolNotFound {
+                name: "wFoo".to_string(),
+            },
+            SaveError::SymbolFileDecompressionFailed,
+            SaveError::SymbolNotInSram {
+                name: "wFoo".to_string(),
+                address: 0xC000,
+            },
+            SaveError::SymbolNotInExpectedRegion {
+                name: "wFoo".to_string(),
+                expected: "WRAM",
+                address: 0xA000,
+            },
+            SaveError::SymbolBeforeBase {
+                symbol: "sFoo".to_string(),
+                base: "sBar".to_string(),
+            },
+            SaveError::UnsupportedMigrationDirection {
+                current_version: 3,
+                target_version: 1,
+            },
+            SaveError::VersionNotSupported {
+                version: 9,
+                supported: vec![1, 2],
+            },
+            SaveError::MissingMigrationStep {
+                from_version: 1,
+                target_version: 3,
+            },
+            SaveError::PatchFailed {
+                id: "p",
+                source: Box::new(SaveError::InvalidBitIndex { bit: 8 }),
+            },
+            SaveError::UnknownFixPatch { dev_type: 9 },
+            SaveError::UnknownPatchId {
+                id: "x".to_string(),
+                known: Vec::new(),
+            },
+            SaveError::DuplicatePatchId { id: "p" },
+            SaveError::NotImplemented {
+                feature: "x".to_string(),
+            },
+            SaveError::ChecksumMismatch {
+                which: "main",
+                stored: 1,
+                calculated: 2,
+            },
+            SaveError::ChecksumMismatch32 {
+                which: "adler32",
+                stored: 1,
+                calculated: 2,
+            },
+            SaveError::CorruptedSave {
+                offset: Address(1),
+                reason: "x".to_string(),
+            },
+            SaveError::InvalidSaveState {
+                reason: "x".to_string(),
+            },
+            SaveError::MultipleErrors(Vec::new()),
+        ];
+
+        let codes: HashSet<&str> = errors.iter().map(error_to_code).collect();
+        assert_eq!(codes.len(), errors.len());
+        for code in codes {
+            assert!(!code.is_empty());
+            assert!(
+                code.chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_'),
+                "{code}"
+            );
+        }
+    }
+
+    #[test]
+    fn ts_types_declare_exported_shapes() {
+        let dts = generate_ts_types();
+        assert!(dts.contains("export type GbSaveLogLevel ="));
+        for name in [
+            "GbSaveLogEntry",
+            "GbSavePatchOutcome",
+            "GbSaveMigrationInfo",
+            "GbSaveFixInfo",
+            "GbSavePatchMetadata",
+            "GbSaveMigrationStep",
+            "GbSaveMigrationPlan",
+        ] {
+            assert!(
+                dts.contains(&format!("export interface {name} {{")),
+                "{name}"
+            );
+        }
+    }
+}
@@ -8,7 +8,7 @@
 //! ## Public API surface
 //!
 //! The stable API is [`js`], which contains helpers for converting patch outcomes and logs into
-//! JS-friendly shapes.
+//! JS-friendly shapes, plus TypeScript declarations for those shapes (`types/index.d.ts`).
 //!
 //! ## Versioning
 //!
@@ -34,5 +34,4 @@
 //! # }
 //! ```
 
-#[cfg(target_arch = "wasm32")]
 pub mod js;
@@ -0,0 +1,81 @@
+// TypeScript declarations for the JS values built by `gb-save-web` (see `src/js.rs`).
+//
+// Hand-maintained: update this file whenever the shapes in `src/js.rs` change. The same text is
+// available from Rust via `gb_save_web::js::generate_ts_types()`.
+
+/** Severity of a patch log entry. */
+export type GbSaveLogLevel = "info" | "warn" | "error";
+
+/** A single patch log entry, as built by `logs_to_js`. */
+export interface GbSaveLogEntry {
+  level: GbSaveLogLevel;
+  /** CSS classes for rendering, e.g. `"gb-save-log gb-save-log--warn"`. */
+  className: string;
+  /** Stable identifier for where the entry came from (usually a patch id). */
+  source: string;
+  message: string;
+}
+
+/** Result of a patch, as built by `patch_outcome_to_js`. */
+export interface GbSavePatchOutcome {
+  ok: boolean;
+  /** Present when `ok` is false. */
+  error?: string;
+  /** Stable error kind (e.g. `"CHECKSUM_MISMATCH"`); present when `ok` is false. */
+  error_code?: string;
+  /** Patched save bytes; present when patching succeeded (unless `diff` is present). */
+  bytes?: Uint8Array;
+  /** Changed bytes relative to the input; set by `patch_outcome_with_diff_to_js` instead of `bytes`. */
+  diff?: GbSaveByteChange[];
+  logs: GbSaveLogEntry[];
+}
+
+/** A single changed byte, as listed by `patch_outcome_with_diff_to_js`. */
+export interface GbSaveByteChange {
+  /** Offset into the save buffer. */
+  address: number;
+  before: number;
+  after: number;
+}
+
+/** A migration patch, as listed by `patch_metadata_to_js`. */
+export interface GbSaveMigrationInfo {
+  id: string;
+  from_version: number;
+  to_version: number;
+  description: string;
+}
+
+/** A fix patch, as listed by `patch_metadata_to_js`. */
+export interface GbSaveFixInfo {
+  id: string;
+  /** The `dev_type` value that selects this fix. */
+  dev_type: number;
+  description: string;
+}
+
+/** The patches a build supports, as built by `patch_metadata_to_js`. */
+export interface GbSavePatchMetadata {
+  migrations: GbSaveMigrationInfo[];
+  fixes: GbSaveFixInfo[];
+  min_version: number;
+  max_version: number;
+}
+
+/** One step of a migration plan, as built by `migration_plan_to_js`. */
+export interface GbSaveMigrationStep {
+  id: string;
+  from: number;
+  to: number;
+}
+
+/** A migration plan preview, as built by `migration_plan_to_js`. */
+export interface GbSaveMigrationPlan {
+  ok: boolean;
+  /** Steps in application order; empty when `ok` is false or no migration is needed. */
+  steps: GbSaveMigrationStep[];
+  /** Present when `ok` is false. */
+  error?: string;
+  /** Stable error kind (e.g. `"MISSING_MIGRATION_STEP"`); present when `ok` is false. */
+  error_code?: string;
+}
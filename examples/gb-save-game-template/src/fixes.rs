@@ -1,4 +1,4 @@
-use gb_save_core::{Patch, PatchKind, PatchLogSink, PatchMetadata, SaveBinary, SaveError, SaveResult, SymbolDatabase};
+use gb_save_core::{Patch, PatchLogSink, PatchMetadata, SaveBinary, SaveError, SaveResult, SymbolDatabase};
 
 const FIX_PATCH_ID: &str = "example.fix.dev_type_1";
 
@@ -7,12 +7,12 @@ pub struct FixDevType1;
 
 impl Patch for FixDevType1 {
     fn metadata(&self) -> PatchMetadata {
-        PatchMetadata {
-            id: FIX_PATCH_ID,
-            kind: PatchKind::Fix,
-            from_version: None,
-            to_version: None,
-        }
+        PatchMetadata::builder()
+            .id(FIX_PATCH_ID)
+            .fix()
+            .description("Placeholder fix patch; the template does not implement any repair logic.")
+            .display_name("Example fix (dev_type 1)")
+            .build()
     }
 
     fn apply(&self, _save: &mut SaveBinary, _symbols: &SymbolDatabase) -> SaveResult<()> {
@@ -17,3 +17,14 @@ pub fn get_save_version(save: &SaveBinary) -> SaveResult<u16> {
     save.require_min_size(MIN_SAVE_SIZE)?;
     save.read_u16_le(Address(SAVE_VERSION_ABS_ADDRESS))
 }
+
+/// Returns a short description of a known save version.
+#[must_use]
+pub fn describe_version(version: u16) -> Option<&'static str> {
+    match version {
+        1 => Some("v1 - initial example release"),
+        2 => Some("v2 - example update adding a migrated field"),
+        3 => Some("v3 - latest example release"),
+        _ => None,
+    }
+}
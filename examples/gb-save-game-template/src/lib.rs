@@ -27,9 +27,12 @@ pub use wasm::*;
 
 pub use gb_save_core::{PatchLogLevel, SaveBinary};
 
-pub use game::{get_save_version, MIN_SAVE_SIZE, SAVE_VERSION_ABS_ADDRESS};
+pub use game::{describe_version, get_save_version, MIN_SAVE_SIZE, SAVE_VERSION_ABS_ADDRESS};
 pub use patcher::{patch_save_bytes, patch_save_bytes_with_log, PatchSaveOutcome};
-pub use symbols::{supported_version_from_u16, symbols_for_version, SupportedSaveVersion};
+pub use symbols::{
+    supported_version_from_u16, symbols_for_version, SupportedSaveVersion, MAX_SUPPORTED_VERSION,
+    MIN_SUPPORTED_VERSION,
+};
 pub use validation::{validate_before_patching, validate_before_patching_with_log};
 
 /// Convenience wrapper suitable for implementing `gb_save_cli::GameCli::detect_version`.
@@ -37,6 +40,14 @@ pub fn detect_version(bytes: &[u8]) -> anyhow::Result<u16> {
     patcher::detect_version_for_cli(bytes)
 }
 
+/// Convenience wrapper suitable for implementing `gb_save_cli::GameCli::validate`.
+pub fn validate(bytes: &[u8]) -> anyhow::Result<()> {
+    let save = SaveBinary::new(bytes.to_vec());
+    validate_before_patching(&save)?;
+    get_save_version(&save)?;
+    Ok(())
+}
+
 /// Convenience wrapper suitable for implementing `gb_save_cli::GameCli::patch`.
 pub fn patch(bytes: Vec<u8>, target_version: u16, dev_type: u8) -> anyhow::Result<Vec<u8>> {
     patcher::patch_save_bytes_for_cli(bytes, target_version, dev_type)
@@ -47,3 +58,45 @@ pub fn patch(bytes: Vec<u8>, target_version: u16, dev_type: u8) -> anyhow::Resul
 pub fn patch_with_log(bytes: Vec<u8>, target_version: u16, dev_type: u8) -> gb_save_cli::PatchOutcome {
     patcher::patch_save_bytes_with_log_for_cli(bytes, target_version, dev_type)
 }
+
+/// Convenience wrapper suitable for implementing `gb_save_cli::GameCli::list_migrations`.
+#[must_use]
+pub fn list_migrations() -> Vec<gb_save_cli::MigrationInfo> {
+    migrations::example_migrations()
+        .into_iter()
+        .filter_map(|patch| {
+            let meta = patch.metadata();
+            Some(gb_save_cli::MigrationInfo {
+                id: meta.id,
+                from_version: meta.from_version?,
+                to_version: meta.to_version?,
+                description: meta.description,
+            })
+        })
+        .collect()
+}
+
+/// Convenience wrapper suitable for implementing `gb_save_cli::GameCli::list_fixes`.
+#[must_use]
+pub fn list_fixes() -> Vec<gb_save_cli::FixInfo> {
+    fixes::example_fix_patches()
+        .into_iter()
+        .map(|fix| {
+            let meta = fix.patch.metadata();
+            gb_save_cli::FixInfo {
+                id: meta.id,
+                dev_type: fix.dev_type,
+                description: meta.description,
+            }
+        })
+        .collect()
+}
+
+/// Convenience wrapper suitable for implementing `gb_save_cli::GameCli::resolve_patch_id`.
+#[must_use]
+pub fn resolve_patch_id(id: &str) -> Option<u8> {
+    fixes::example_fix_patches()
+        .into_iter()
+        .find(|fix| fix.patch.metadata().id == id)
+        .map(|fix| fix.dev_type)
+}
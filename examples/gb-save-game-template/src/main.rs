@@ -9,6 +9,22 @@ impl gb_save_cli::GameCli for ExampleGameCli {
         gb_save_game_template::detect_version(bytes)
     }
 
+    fn validate(bytes: &[u8]) -> Result<()> {
+        gb_save_game_template::validate(bytes)
+    }
+
+    fn min_supported_version() -> u16 {
+        gb_save_game_template::MIN_SUPPORTED_VERSION
+    }
+
+    fn max_supported_version() -> u16 {
+        gb_save_game_template::MAX_SUPPORTED_VERSION
+    }
+
+    fn describe_version(version: u16) -> Option<String> {
+        gb_save_game_template::describe_version(version).map(str::to_string)
+    }
+
     fn patch(bytes: Vec<u8>, target: u16, dev_type: u8) -> Result<Vec<u8>> {
         gb_save_game_template::patch(bytes, target, dev_type)
     }
@@ -16,8 +32,20 @@ impl gb_save_cli::GameCli for ExampleGameCli {
     fn patch_with_log(bytes: Vec<u8>, target: u16, dev_type: u8) -> gb_save_cli::PatchOutcome {
         gb_save_game_template::patch_with_log(bytes, target, dev_type)
     }
+
+    fn list_migrations() -> Vec<gb_save_cli::MigrationInfo> {
+        gb_save_game_template::list_migrations()
+    }
+
+    fn list_fixes() -> Vec<gb_save_cli::FixInfo> {
+        gb_save_game_template::list_fixes()
+    }
+
+    fn resolve_patch_id(id: &str) -> Option<u8> {
+        gb_save_game_template::resolve_patch_id(id)
+    }
 }
 
-fn main() -> Result<()> {
-    gb_save_cli::run::<ExampleGameCli>()
+fn main() -> std::process::ExitCode {
+    gb_save_cli::run_and_report::<ExampleGameCli>()
 }
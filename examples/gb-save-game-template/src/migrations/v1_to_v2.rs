@@ -1,4 +1,4 @@
-use gb_save_core::{Patch, PatchKind, PatchLogSink, PatchMetadata, SaveBinary, SaveResult, SymbolDatabase};
+use gb_save_core::{Patch, PatchLogSink, PatchMetadata, SaveBinary, SaveResult, SymbolDatabase};
 
 use crate::game::SAVE_VERSION_ABS_ADDRESS;
 
@@ -9,12 +9,12 @@ pub struct MigrateV1ToV2;
 
 impl Patch for MigrateV1ToV2 {
     fn metadata(&self) -> PatchMetadata {
-        PatchMetadata {
-            id: PATCH_ID,
-            kind: PatchKind::Migration,
-            from_version: Some(1),
-            to_version: Some(2),
-        }
+        PatchMetadata::builder()
+            .id(PATCH_ID)
+            .migration(1, 2)
+            .description("Upgrades an example save from version 1 to version 2.")
+            .display_name("Migrate v1 to v2")
+            .build()
     }
 
     fn apply(&self, save: &mut SaveBinary, _symbols: &SymbolDatabase) -> SaveResult<()> {
@@ -33,3 +33,17 @@ impl Patch for MigrateV1ToV2 {
 }
 
 pub static MIGRATE_V1_TO_V2: MigrateV1ToV2 = MigrateV1ToV2;
+
+#[cfg(test)]
+mod tests {
+    use gb_save_core::{Address, Patch, SaveBinary, SymbolDatabase};
+
+    #[test]
+    fn writes_only_the_version_field() {
+        let mut save = SaveBinary::new(vec![1, 0, 0xAA, 0xBB]).into_recording();
+        super::MIGRATE_V1_TO_V2.apply(&mut save, &SymbolDatabase::new()).unwrap();
+
+        assert_eq!(save.writes(), &[(Address(0), 1, 2), (Address(1), 0, 0)]);
+        assert_eq!(save.as_bytes(), &[2, 0, 0xAA, 0xBB]);
+    }
+}
@@ -1,4 +1,4 @@
-use gb_save_core::{Patch, PatchKind, PatchLogSink, PatchMetadata, SaveBinary, SaveResult, SymbolDatabase};
+use gb_save_core::{Patch, PatchLogSink, PatchMetadata, SaveBinary, SaveResult, SymbolDatabase};
 
 use crate::game::SAVE_VERSION_ABS_ADDRESS;
 
@@ -9,12 +9,12 @@ pub struct MigrateV2ToV3;
 
 impl Patch for MigrateV2ToV3 {
     fn metadata(&self) -> PatchMetadata {
-        PatchMetadata {
-            id: PATCH_ID,
-            kind: PatchKind::Migration,
-            from_version: Some(2),
-            to_version: Some(3),
-        }
+        PatchMetadata::builder()
+            .id(PATCH_ID)
+            .migration(2, 3)
+            .description("Upgrades an example save from version 2 to version 3.")
+            .display_name("Migrate v2 to v3")
+            .build()
     }
 
     fn apply(&self, save: &mut SaveBinary, _symbols: &SymbolDatabase) -> SaveResult<()> {
@@ -33,3 +33,17 @@ impl Patch for MigrateV2ToV3 {
 }
 
 pub static MIGRATE_V2_TO_V3: MigrateV2ToV3 = MigrateV2ToV3;
+
+#[cfg(test)]
+mod tests {
+    use gb_save_core::{Address, Patch, SaveBinary, SymbolDatabase};
+
+    #[test]
+    fn writes_only_the_version_field() {
+        let mut save = SaveBinary::new(vec![2, 0, 0xAA, 0xBB]).into_recording();
+        super::MIGRATE_V2_TO_V3.apply(&mut save, &SymbolDatabase::new()).unwrap();
+
+        assert_eq!(save.writes(), &[(Address(0), 2, 3), (Address(1), 0, 0)]);
+        assert_eq!(save.as_bytes(), &[3, 0, 0xAA, 0xBB]);
+    }
+}
@@ -12,7 +12,8 @@ const PATCHER_LOG_SOURCE: &str = "example.patcher";
 pub struct PatchSaveOutcome {
     pub bytes: Option<Vec<u8>>,
     pub logs: Vec<PatchLogEntry>,
-    pub error: Option<String>,
+    pub error: Option<SaveError>,
+    pub applied_patch_ids: Vec<&'static str>,
 }
 
 impl From<PatchSaveOutcome> for gb_save_cli::PatchOutcome {
@@ -20,8 +21,10 @@ impl From<PatchSaveOutcome> for gb_save_cli::PatchOutcome {
         Self {
             ok: value.error.is_none(),
             bytes: value.bytes,
-            error: value.error,
+            error: value.error.as_ref().map(ToString::to_string),
+            save_error: value.error,
             logs: value.logs,
+            applied_patch_ids: value.applied_patch_ids.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -89,48 +92,53 @@ pub fn patch_save_bytes_with_log(
     let mut save = SaveBinary::new(bytes);
 
     if let Err(e) = crate::validation::validate_before_patching_with_log(&save, &mut log) {
-        let msg = e.to_string();
-        log.error(PATCHER_LOG_SOURCE, &msg);
+        log.error(PATCHER_LOG_SOURCE, &e.to_string());
         return PatchSaveOutcome {
             bytes: None,
             logs: log.into_entries(),
-            error: Some(msg),
+            error: Some(e),
+            applied_patch_ids: Vec::new(),
         };
     }
 
     let current_version = match get_save_version(&save) {
         Ok(v) => v,
         Err(e) => {
-            let msg = e.to_string();
-            log.error(PATCHER_LOG_SOURCE, &msg);
+            log.error(PATCHER_LOG_SOURCE, &e.to_string());
             return PatchSaveOutcome {
                 bytes: None,
                 logs: log.into_entries(),
-                error: Some(msg),
+                error: Some(e),
+                applied_patch_ids: Vec::new(),
             };
         }
     };
 
     if dev_type != 0 {
         if target_version != current_version {
-            let msg = "fix patches do not migrate; target_version must match current save version";
-            log.error(PATCHER_LOG_SOURCE, msg);
+            let e = SaveError::InvalidSaveState {
+                reason: "fix patches do not migrate; target_version must match current save version"
+                    .to_string(),
+            };
+            log.error(PATCHER_LOG_SOURCE, &e.to_string());
             return PatchSaveOutcome {
                 bytes: None,
                 logs: log.into_entries(),
-                error: Some(msg.to_string()),
+                error: Some(e),
+                applied_patch_ids: Vec::new(),
             };
         }
 
         let fix = match example_fix_patches().into_iter().find(|p| p.dev_type == dev_type) {
             Some(p) => p,
             None => {
-                let msg = SaveError::UnknownFixPatch { dev_type }.to_string();
-                log.error(PATCHER_LOG_SOURCE, &msg);
+                let e = SaveError::UnknownFixPatch { dev_type };
+                log.error(PATCHER_LOG_SOURCE, &e.to_string());
                 return PatchSaveOutcome {
                     bytes: None,
                     logs: log.into_entries(),
-                    error: Some(msg),
+                    error: Some(e),
+                    applied_patch_ids: Vec::new(),
                 };
             }
         };
@@ -147,23 +155,23 @@ pub fn patch_save_bytes_with_log(
         {
             Ok(s) => s,
             Err(e) => {
-                let msg = e.to_string();
-                log.error(PATCHER_LOG_SOURCE, &msg);
+                log.error(PATCHER_LOG_SOURCE, &e.to_string());
                 return PatchSaveOutcome {
                     bytes: None,
                     logs: log.into_entries(),
-                    error: Some(msg),
+                    error: Some(e),
+                    applied_patch_ids: Vec::new(),
                 };
             }
         };
 
         if let Err(e) = fix.patch.apply_with_log(&mut save, &symbols, &mut log) {
-            let msg = e.to_string();
-            log.error(fix.patch.metadata().id, &msg);
+            log.error(fix.patch.metadata().id, &e.to_string());
             return PatchSaveOutcome {
                 bytes: None,
                 logs: log.into_entries(),
-                error: Some(msg),
+                error: Some(e),
+                applied_patch_ids: Vec::new(),
             };
         }
 
@@ -171,6 +179,7 @@ pub fn patch_save_bytes_with_log(
             bytes: Some(save.into_bytes()),
             logs: log.into_entries(),
             error: None,
+            applied_patch_ids: vec![fix.patch.metadata().id],
         };
     }
 
@@ -179,6 +188,7 @@ pub fn patch_save_bytes_with_log(
             bytes: Some(save.into_bytes()),
             logs: log.into_entries(),
             error: None,
+            applied_patch_ids: Vec::new(),
         };
     }
 
@@ -186,26 +196,23 @@ pub fn patch_save_bytes_with_log(
     let plan = match resolve_migration_plan(&migrations, current_version, target_version) {
         Ok(p) => p,
         Err(e) => {
-            let msg = e.to_string();
-            log.error(PATCHER_LOG_SOURCE, &msg);
+            log.error(PATCHER_LOG_SOURCE, &e.to_string());
             return PatchSaveOutcome {
                 bytes: None,
                 logs: log.into_entries(),
-                error: Some(msg),
+                error: Some(e),
+                applied_patch_ids: Vec::new(),
             };
         }
     };
 
-    let plan_ids = plan
-        .iter()
-        .map(|p| p.metadata().id)
-        .collect::<Vec<_>>()
-        .join(" -> ");
+    let plan_ids = plan.ids().join(" -> ");
     log.info(
         PATCHER_LOG_SOURCE,
         &format!("migration plan {current_version} -> {target_version}: {plan_ids}"),
     );
 
+    let mut applied_patch_ids = Vec::with_capacity(plan.len());
     for patch in plan {
         let meta = patch.metadata();
         let from = meta
@@ -215,31 +222,33 @@ pub fn patch_save_bytes_with_log(
         let symbols = match supported_version_from_u16(from).and_then(symbols_for_version) {
             Ok(s) => s,
             Err(e) => {
-                let msg = e.to_string();
-                log.error(PATCHER_LOG_SOURCE, &msg);
+                log.error(PATCHER_LOG_SOURCE, &e.to_string());
                 return PatchSaveOutcome {
                     bytes: None,
                     logs: log.into_entries(),
-                    error: Some(msg),
+                    error: Some(e),
+                    applied_patch_ids: Vec::new(),
                 };
             }
         };
 
         if let Err(e) = patch.apply_with_log(&mut save, &symbols, &mut log) {
-            let msg = e.to_string();
-            log.error(meta.id, &msg);
+            log.error(meta.id, &e.to_string());
             return PatchSaveOutcome {
                 bytes: None,
                 logs: log.into_entries(),
-                error: Some(msg),
+                error: Some(e),
+                applied_patch_ids: Vec::new(),
             };
         }
+        applied_patch_ids.push(meta.id);
     }
 
     PatchSaveOutcome {
         bytes: Some(save.into_bytes()),
         logs: log.into_entries(),
         error: None,
+        applied_patch_ids,
     }
 }
 
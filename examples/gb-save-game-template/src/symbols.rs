@@ -19,6 +19,12 @@ impl SupportedSaveVersion {
     }
 }
 
+/// Oldest save version this example game supports.
+pub const MIN_SUPPORTED_VERSION: u16 = 1;
+
+/// Newest save version this example game supports.
+pub const MAX_SUPPORTED_VERSION: u16 = 3;
+
 /// Converts a raw `u16` into a supported version.
 ///
 /// # Errors
@@ -28,8 +34,9 @@ pub fn supported_version_from_u16(version: u16) -> SaveResult<SupportedSaveVersi
         1 => Ok(SupportedSaveVersion::V1),
         2 => Ok(SupportedSaveVersion::V2),
         3 => Ok(SupportedSaveVersion::V3),
-        _ => Err(SaveError::InvalidSaveState {
-            reason: format!("unsupported save version {version}"),
+        _ => Err(SaveError::VersionNotSupported {
+            version,
+            supported: (MIN_SUPPORTED_VERSION..=MAX_SUPPORTED_VERSION).collect(),
         }),
     }
 }
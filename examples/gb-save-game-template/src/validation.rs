@@ -1,17 +1,30 @@
-use gb_save_core::{PatchLogSink, SaveBinary, SaveResult};
+use gb_save_core::{collect_errors, PatchLogSink, SaveBinary, SaveResult};
+
+use crate::game::{get_save_version, MIN_SAVE_SIZE};
+use crate::symbols::supported_version_from_u16;
 
 const VALIDATION_LOG_SOURCE: &str = "example.validation";
 
 /// Example "validation" hook.
 ///
 /// Real games often validate primary and backup checksums before patching.
-/// This template provides a named entry point that can be expanded.
+/// Every check runs, so callers see all failures at once; add new checks to the list.
 ///
 /// # Errors
-/// Returns an error if the save is too small or otherwise invalid.
+/// Returns the failed check's error, or `SaveError::MultipleErrors` if several checks failed.
 pub fn validate_before_patching(save: &SaveBinary) -> SaveResult<()> {
-    let _ = save;
-    Ok(())
+    collect_errors([
+        save.require_min_size(MIN_SAVE_SIZE),
+        check_version_supported(save),
+    ])
+}
+
+fn check_version_supported(save: &SaveBinary) -> SaveResult<()> {
+    match get_save_version(save) {
+        Ok(version) => supported_version_from_u16(version).map(|_| ()),
+        // A save too small to hold a version is already reported by the size check.
+        Err(_) => Ok(()),
+    }
 }
 
 /// Like [`validate_before_patching`], but emits structured logs.
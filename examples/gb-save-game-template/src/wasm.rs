@@ -1,5 +1,8 @@
+use gb_save_core::{PatchLogSink, SaveBinary, VecPatchLogSink};
 use wasm_bindgen::prelude::*;
 
+const WASM_LOG_SOURCE: &str = "example.wasm";
+
 /// Returns the detected save version.
 ///
 /// # Errors
@@ -20,6 +23,35 @@ pub fn patch_save(bytes: &[u8], target_version: u16, dev_type: u8) -> Result<Vec
         .map_err(|e| JsValue::from_str(&e.to_string()))
 }
 
+/// [`patch_save`] for callers that already hold a `Uint8Array`.
+///
+/// Memory model: the input is copied once into WASM memory (`Uint8Array::to_vec`), patched there,
+/// and the result is copied once into a fresh JS-owned `Uint8Array`. Despite the name, the
+/// caller's array is never modified, and no view into WASM memory escapes (such views are
+/// invalidated when WASM memory grows).
+///
+/// # Errors
+/// Returns a JavaScript error string if patching fails.
+#[wasm_bindgen]
+pub fn patch_save_in_place(
+    bytes: js_sys::Uint8Array,
+    target_version: u16,
+    dev_type: u8,
+) -> Result<js_sys::Uint8Array, JsValue> {
+    let patched = patch_save(&bytes.to_vec(), target_version, dev_type)?;
+    Ok(js_sys::Uint8Array::from(patched.as_slice()))
+}
+
+/// Returns the save size in bytes, as a hint for pre-allocating buffers.
+///
+/// This example only requires [`crate::MIN_SAVE_SIZE`] bytes; a real game would return its
+/// cartridge SRAM size (e.g. `0x8000`).
+#[wasm_bindgen]
+#[must_use]
+pub fn get_save_size() -> usize {
+    crate::MIN_SAVE_SIZE
+}
+
 /// Applies a patch and returns a structured result object.
 #[wasm_bindgen]
 pub fn patch_save_with_log(bytes: &[u8], target_version: u16, dev_type: u8) -> JsValue {
@@ -27,6 +59,173 @@ pub fn patch_save_with_log(bytes: &[u8], target_version: u16, dev_type: u8) -> J
     gb_save_web::js::patch_outcome_to_js(
         outcome.bytes.as_deref(),
         &outcome.logs,
-        outcome.error.as_deref(),
+        outcome.error.as_ref(),
+    )
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(callback: &js_sys::Function, delay_ms: i32);
+}
+
+/// Yields to the JS event loop (a `setTimeout(0)` macrotask) so the page can repaint.
+async fn yield_to_event_loop() {
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| set_timeout(&resolve, 0));
+    // `resolve` is only ever called with `undefined`, so this cannot reject.
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+/// Async variant of [`patch_save_with_log`]; the JS export returns a `Promise`.
+///
+/// Yields to the event loop once before patching so callers can update the UI first.
+#[wasm_bindgen]
+pub async fn patch_save_bytes_async(bytes: Vec<u8>, target_version: u16, dev_type: u8) -> JsValue {
+    yield_to_event_loop().await;
+    patch_save_with_log(&bytes, target_version, dev_type)
+}
+
+/// Validates a save without patching it and returns a structured result object.
+///
+/// The result has the same shape as [`patch_save_with_log`] (a `GbSavePatchOutcome`) but never
+/// includes `bytes`.
+#[wasm_bindgen]
+pub fn validate_save(bytes: &[u8]) -> JsValue {
+    let save = SaveBinary::new(bytes.to_vec());
+    let mut log = VecPatchLogSink::new();
+
+    let result = crate::patcher::validate_before_patching_with_log_for_patcher(&save, &mut log)
+        .and_then(|()| crate::game::get_save_version(&save));
+    let error = result.err();
+    if let Some(e) = &error {
+        log.error(WASM_LOG_SOURCE, &e.to_string());
+    }
+
+    gb_save_web::js::patch_outcome_to_js(None, &log.into_entries(), error.as_ref())
+}
+
+/// Lists the migrations and fix patches this build supports.
+///
+/// Returns a `GbSavePatchMetadata` object so frontends do not need to hardcode version lists.
+#[wasm_bindgen]
+pub fn get_patch_metadata() -> JsValue {
+    let migrations = crate::migrations::example_migrations()
+        .into_iter()
+        .map(|patch| patch.metadata())
+        .collect::<Vec<_>>();
+    let fixes = crate::fixes::example_fix_patches()
+        .into_iter()
+        .map(|fix| (fix.dev_type, fix.patch.metadata()))
+        .collect::<Vec<_>>();
+
+    gb_save_web::js::patch_metadata_to_js(
+        &migrations,
+        &fixes,
+        crate::symbols::MIN_SUPPORTED_VERSION,
+        crate::symbols::MAX_SUPPORTED_VERSION,
     )
 }
+
+/// Previews the migrations that would run to go from `from_version` to `to_version`.
+///
+/// Returns a `GbSaveMigrationPlan` object; `ok` is false if no plan exists.
+#[wasm_bindgen]
+pub fn get_migration_plan(from_version: u16, to_version: u16) -> JsValue {
+    let migrations = crate::migrations::example_migrations();
+    let plan = gb_save_core::resolve_migration_plan(&migrations, from_version, to_version);
+    gb_save_web::js::migration_plan_to_js(&plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use js_sys::Reflect;
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn get(value: &JsValue, key: &str) -> JsValue {
+        Reflect::get(value, &JsValue::from_str(key)).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    async fn patch_save_bytes_async_matches_sync_patch() {
+        let out = super::patch_save_bytes_async(vec![1, 0, 0, 0], 2, 0).await;
+        assert!(get(&out, "error").is_undefined());
+        let bytes = js_sys::Uint8Array::new(&get(&out, "bytes")).to_vec();
+        assert_eq!(bytes, super::patch_save(&[1, 0, 0, 0], 2, 0).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_save_in_place_matches_patch_save() {
+        let input = js_sys::Uint8Array::from(&[1u8, 0, 0, 0][..]);
+        let out = super::patch_save_in_place(input.clone(), 2, 0).unwrap();
+        assert_eq!(out.to_vec(), super::patch_save(&[1, 0, 0, 0], 2, 0).unwrap());
+        assert_eq!(input.to_vec(), vec![1, 0, 0, 0]);
+        assert!(super::get_save_size() >= crate::MIN_SAVE_SIZE);
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_save_accepts_supported_save() {
+        let out = super::validate_save(&[1, 0, 0, 0]);
+        assert_eq!(get(&out, "ok").as_bool(), Some(true));
+        assert!(get(&out, "error").is_undefined());
+        assert!(get(&out, "bytes").is_undefined());
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_save_reports_errors() {
+        let out = super::validate_save(&[1]);
+        assert_eq!(get(&out, "ok").as_bool(), Some(false));
+        assert!(get(&out, "error").as_string().is_some());
+        assert_eq!(
+            get(&out, "error_code").as_string().as_deref(),
+            Some("SAVE_TOO_SMALL")
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn patch_metadata_lists_migrations() {
+        let out = super::get_patch_metadata();
+        let migrations = get(&out, "migrations");
+        assert!(js_sys::Array::is_array(&migrations));
+        assert_eq!(js_sys::Array::from(&migrations).length(), 2);
+        assert_eq!(get(&out, "min_version").as_f64(), Some(1.0));
+    }
+
+    fn step_ids(plan: &JsValue) -> Vec<String> {
+        js_sys::Array::from(&get(plan, "steps"))
+            .iter()
+            .map(|step| get(&step, "id").as_string().unwrap())
+            .collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn migration_plan_lists_steps() {
+        let plan = super::get_migration_plan(1, 3);
+        assert_eq!(get(&plan, "ok").as_bool(), Some(true));
+        assert_eq!(
+            step_ids(&plan),
+            vec!["example.migration.v1_to_v2", "example.migration.v2_to_v3"]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn impossible_migration_plan_is_not_ok() {
+        let plan = super::get_migration_plan(3, 1);
+        assert_eq!(get(&plan, "ok").as_bool(), Some(false));
+        assert!(get(&plan, "error").as_string().is_some());
+        assert_eq!(
+            get(&plan, "error_code").as_string().as_deref(),
+            Some("UNSUPPORTED_MIGRATION_DIRECTION")
+        );
+        assert!(step_ids(&plan).is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn same_version_migration_plan_is_empty() {
+        let plan = super::get_migration_plan(2, 2);
+        assert_eq!(get(&plan, "ok").as_bool(), Some(true));
+        assert!(step_ids(&plan).is_empty());
+    }
+}